@@ -0,0 +1,38 @@
+use core::mem::MaybeUninit;
+
+use generic_array::{ArrayLength, GenericArray};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Capacity of a [`Dequeue`](super::Dequeue), along with the storage it is backed by.
+///
+/// Implemented for `typenum` unsigned integers, backed by [`GenericArray`], and for [`ConstCapacity`], backed by a plain array.
+pub trait Capacity: sealed::Sealed {
+    /// Maximum number of stored elements.
+    const CAPACITY: usize;
+
+    /// Array of exactly `CAPACITY` possibly uninitialized elements.
+    type Storage<T>: AsRef<[MaybeUninit<T>]> + AsMut<[MaybeUninit<T>]>;
+}
+
+impl<N: ArrayLength> sealed::Sealed for N {}
+
+impl<N: ArrayLength> Capacity for N {
+    const CAPACITY: usize = N::USIZE;
+
+    type Storage<T> = GenericArray<MaybeUninit<T>, N>;
+}
+
+/// Capacity, specified as a const generic, see [`Capacity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ConstCapacity<const N: usize>;
+
+impl<const N: usize> sealed::Sealed for ConstCapacity<N> {}
+
+impl<const N: usize> Capacity for ConstCapacity<N> {
+    const CAPACITY: usize = N;
+
+    type Storage<T> = [MaybeUninit<T>; N];
+}
@@ -0,0 +1,119 @@
+use core::{marker::PhantomData, mem::ManuallyDrop};
+
+use generic_array::{typenum::Const, ArrayLength, GenericArray, IntoArrayLength};
+
+use super::{Bounded, Capacity, ConstCapacity, Dequeue, Wrapping};
+
+impl<T, N: Capacity> Dequeue<T, N> {
+    /// Creates a dequeue, containing all of the array elements in order.
+    ///
+    /// Array may be shorter than the capacity, but not longer - that is a compile error.
+    pub fn from_array<const M: usize>(array: [T; M]) -> Self {
+        const {
+            assert!(
+                M <= N::CAPACITY,
+                "Array does not fit into the dequeue capacity"
+            );
+        }
+        let array = ManuallyDrop::new(array);
+        // SAFETY:
+        // Array contains exactly `M` valid elements, that are not dropped, and `M` fits into the capacity
+        unsafe { Self::from_raw_parts(array.as_ptr(), M) }
+    }
+
+    /// Bitwise copies `len` elements from `src` into a new dequeue.
+    ///
+    /// # Safety
+    /// `src` must be valid for reading `len` elements, that are considered moved afterwards.
+    /// `len` must not be greater than the capacity.
+    unsafe fn from_raw_parts(src: *const T, len: usize) -> Self {
+        debug_assert!(len <= N::CAPACITY);
+        let mut data = Self::uninit_storage();
+        core::ptr::copy_nonoverlapping(src, data.as_mut().as_mut_ptr().cast::<T>(), len);
+        Self {
+            data,
+            start: Wrapping::ZERO,
+            len: Bounded(len, PhantomData),
+        }
+    }
+
+    /// Unwraps into the underlying storage, with elements moved to its beginning, and their number.
+    ///
+    /// Elements are initialized at positions from `0` to `len-1`, and are not dropped, unless caller does so.
+    pub fn into_array_partial(self) -> (N::Storage<T>, usize) {
+        let mut this = ManuallyDrop::new(self);
+        this.make_contiguous();
+        // SAFETY:
+        // Dequeue is not used (or dropped) after this read, so elements are not duplicated
+        let data = unsafe { core::ptr::read(&this.data) };
+        (data, *this.len)
+    }
+
+    /// Unwraps into an array of elements, if the dequeue is full.
+    ///
+    /// # Safety
+    /// `A` must be an array of exactly `N` elements of `T`.
+    unsafe fn into_full_array<A>(self) -> Result<A, Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+        let (data, _) = self.into_array_partial();
+        // SAFETY:
+        // All of the positions are initialized, and storage is laid out the same way as the array.
+        // Storage consists of `MaybeUninit`, so nothing is dropped here.
+        Ok(core::mem::transmute_copy::<N::Storage<T>, A>(&data))
+    }
+}
+
+impl<T, N: ArrayLength> From<GenericArray<T, N>> for Dequeue<T, N> {
+    #[inline]
+    fn from(array: GenericArray<T, N>) -> Self {
+        let array = ManuallyDrop::new(array);
+        // SAFETY:
+        // Array contains exactly `N` valid elements, that are not dropped
+        unsafe { Self::from_raw_parts(array.as_ptr(), N::USIZE) }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Dequeue<T, ConstCapacity<N>> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+/// Succeeds only if the dequeue is full, handing it back otherwise.
+impl<T, N: ArrayLength> TryFrom<Dequeue<T, N>> for GenericArray<T, N> {
+    type Error = Dequeue<T, N>;
+
+    #[inline]
+    fn try_from(dequeue: Dequeue<T, N>) -> Result<Self, Self::Error> {
+        // SAFETY: `GenericArray` of `N` elements is an array of `N` elements
+        unsafe { dequeue.into_full_array() }
+    }
+}
+
+/// Succeeds only if the dequeue is full, handing it back otherwise.
+impl<T, N: ArrayLength, const M: usize> TryFrom<Dequeue<T, N>> for [T; M]
+where
+    Const<M>: IntoArrayLength<ArrayLength = N>,
+{
+    type Error = Dequeue<T, N>;
+
+    #[inline]
+    fn try_from(dequeue: Dequeue<T, N>) -> Result<Self, Self::Error> {
+        // SAFETY: `M` is statically the same number as `N`
+        unsafe { dequeue.into_full_array() }
+    }
+}
+
+/// Succeeds only if the dequeue is full, handing it back otherwise.
+impl<T, const N: usize> TryFrom<Dequeue<T, ConstCapacity<N>>> for [T; N] {
+    type Error = Dequeue<T, ConstCapacity<N>>;
+
+    #[inline]
+    fn try_from(dequeue: Dequeue<T, ConstCapacity<N>>) -> Result<Self, Self::Error> {
+        // SAFETY: capacity is exactly `N`
+        unsafe { dequeue.into_full_array() }
+    }
+}
@@ -0,0 +1,39 @@
+use std::io::{BufRead, Read, Write};
+
+use super::{Capacity, Dequeue};
+
+/// Writes bytes to the back, as many as there's space for.
+///
+/// Once the dequeue is full, writes return `Ok(0)`.
+impl<N: Capacity> Write for Dequeue<u8, N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.push_back_from_slice(buf))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reads bytes from the front.
+impl<N: Capacity> Read for Dequeue<u8, N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.pop_front_into_slice(buf))
+    }
+}
+
+/// Exposes bytes from the front, up to the physical end of the underlying array.
+impl<N: Capacity> BufRead for Dequeue<u8, N> {
+    #[inline]
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(self.as_slices().0)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.truncate_front(self.len().saturating_sub(amt));
+    }
+}
@@ -0,0 +1,245 @@
+use core::{fmt::Debug, iter::FusedIterator, marker::PhantomData, ops::Range, slice};
+
+use super::{Bounded, Capacity, Dequeue};
+
+/// Borrowing iterator over [`Dequeue`] elements, see [`Dequeue::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'d, T> {
+    first: slice::Iter<'d, T>,
+    second: slice::Iter<'d, T>,
+}
+
+impl<'d, T> Iter<'d, T> {
+    #[inline]
+    pub(super) fn new(first: &'d [T], second: &'d [T]) -> Self {
+        Self {
+            first: first.iter(),
+            second: second.iter(),
+        }
+    }
+}
+
+impl<'d, T> Iterator for Iter<'d, T> {
+    type Item = &'d T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first.next().or_else(|| self.second.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.second.next_back().or_else(|| self.first.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Mutably borrowing iterator over [`Dequeue`] elements, see [`Dequeue::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'d, T> {
+    first: slice::IterMut<'d, T>,
+    second: slice::IterMut<'d, T>,
+}
+
+impl<'d, T> IterMut<'d, T> {
+    #[inline]
+    pub(super) fn new(first: &'d mut [T], second: &'d mut [T]) -> Self {
+        Self {
+            first: first.iter_mut(),
+            second: second.iter_mut(),
+        }
+    }
+}
+
+impl<'d, T> Iterator for IterMut<'d, T> {
+    type Item = &'d mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first.next().or_else(|| self.second.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.second.next_back().or_else(|| self.first.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// Owning iterator over [`Dequeue`] elements, see [`Dequeue::into_iter`].
+pub struct IntoIter<T, N: Capacity> {
+    dequeue: Dequeue<T, N>,
+}
+
+impl<T, N: Capacity> IntoIter<T, N> {
+    #[inline]
+    pub(super) fn new(dequeue: Dequeue<T, N>) -> Self {
+        Self { dequeue }
+    }
+}
+
+impl<T: Debug, N: Capacity> Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.dequeue).finish()
+    }
+}
+
+impl<T: Clone, N: Capacity> Clone for IntoIter<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            dequeue: self.dequeue.clone(),
+        }
+    }
+}
+
+impl<T, N: Capacity> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.dequeue.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.dequeue.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, N: Capacity> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.dequeue.pop_back()
+    }
+}
+
+impl<T, N: Capacity> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, N: Capacity> FusedIterator for IntoIter<T, N> {}
+
+/// Draining iterator over a range of [`Dequeue`] elements, see [`Dequeue::try_drain`].
+pub struct Drain<'d, T, N: Capacity> {
+    dequeue: &'d mut Dequeue<T, N>,
+    /// Logical range being drained
+    range: Range<usize>,
+    /// Logical range of elements, that are not yet yielded
+    remaining: Range<usize>,
+    /// Length of the dequeue, before draining
+    orig_len: usize,
+}
+
+impl<'d, T, N: Capacity> Drain<'d, T, N> {
+    pub(super) fn new(dequeue: &'d mut Dequeue<T, N>, range: Range<usize>) -> Self {
+        let orig_len = dequeue.len();
+        // while draining, only the elements before the range are considered valid.
+        //
+        // if drain is leaked, elements after the range are leaked too, but no double-drops can occur
+        dequeue.len = Bounded(range.start, PhantomData);
+        Self {
+            dequeue,
+            range: range.clone(),
+            remaining: range,
+            orig_len,
+        }
+    }
+}
+
+impl<T: Debug, N: Capacity> Debug for Drain<'_, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Drain")
+            .field("range", &self.range)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<T, N: Capacity> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.remaining.next()?;
+        // SAFETY:
+        // Positions in the remaining range contain valid data, and are not yielded twice
+        unsafe { Some(self.dequeue.take_at(Bounded(pos, PhantomData))) }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl<T, N: Capacity> DoubleEndedIterator for Drain<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let pos = self.remaining.next_back()?;
+        // SAFETY:
+        // Positions in the remaining range contain valid data, and are not yielded twice
+        unsafe { Some(self.dequeue.take_at(Bounded(pos, PhantomData))) }
+    }
+}
+
+impl<T, N: Capacity> ExactSizeIterator for Drain<'_, T, N> {}
+
+impl<T, N: Capacity> FusedIterator for Drain<'_, T, N> {}
+
+impl<T, N: Capacity> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        // drop whatever was not consumed
+        self.for_each(drop);
+
+        let removed = self.range.len();
+        let head_len = self.range.start;
+        let tail_len = self.orig_len - self.range.end;
+
+        // close the gap, moving the shorter side
+        //
+        // SAFETY:
+        // Logical positions before and after the drained range still contain valid data.
+        // Drained positions are no longer initialized, so they may be overwritten.
+        unsafe {
+            if head_len < tail_len {
+                self.dequeue.move_within(0, removed, head_len);
+                self.dequeue.start += Bounded(removed, PhantomData);
+            } else {
+                self.dequeue
+                    .move_within(self.range.end, self.range.start, tail_len);
+            }
+        }
+        self.dequeue.len = Bounded(self.orig_len - removed, PhantomData);
+    }
+}
@@ -1,17 +1,43 @@
 use core::{
     fmt::Debug,
+    hash::{Hash, Hasher},
     marker::PhantomData,
     mem::MaybeUninit,
-    ops::{Add, AddAssign, Deref, Index, IndexMut},
+    ops::{Add, AddAssign, Bound, Deref, Range, RangeBounds},
 };
 
-use generic_array::{ArrayLength, GenericArray};
+mod capacity;
+mod convert;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+mod ring_log;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
-#[derive(Debug, Clone, Copy)]
+pub use capacity::{Capacity, ConstCapacity};
+pub use iter::{Drain, IntoIter, Iter, IterMut};
+pub use ring_log::RingLog;
+
+use crate::Error;
+
+/// Physical position in the `data` array, wrapping around its end.
+///
+/// With zero capacity there are no positions at all, so the position stays at `0`.
+#[derive(Debug)]
 #[repr(transparent)]
-struct Wrapping<N: ArrayLength>(usize, PhantomData<N>);
+struct Wrapping<N: Capacity>(usize, PhantomData<N>);
 
-impl<N: ArrayLength> Deref for Wrapping<N> {
+impl<N: Capacity> Clone for Wrapping<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N: Capacity> Copy for Wrapping<N> {}
+
+impl<N: Capacity> Deref for Wrapping<N> {
     type Target = usize;
 
     #[inline]
@@ -20,12 +46,12 @@ impl<N: ArrayLength> Deref for Wrapping<N> {
     }
 }
 
-impl<N: ArrayLength> Wrapping<N> {
+impl<N: Capacity> Wrapping<N> {
     const ZERO: Self = Self(0, PhantomData);
 
     #[inline]
     const fn inc(&mut self) {
-        if self.0 == N::USIZE - 1 {
+        if self.0 + 1 >= N::CAPACITY {
             self.0 = 0;
         } else {
             self.0 += 1;
@@ -37,22 +63,31 @@ impl<N: ArrayLength> Wrapping<N> {
         if let Some(m1) = self.0.checked_sub(1) {
             self.0 = m1;
         } else {
-            self.0 = N::USIZE - 1;
+            self.0 = N::CAPACITY.saturating_sub(1);
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug)]
 #[repr(transparent)]
-struct Bounded<N: ArrayLength>(usize, PhantomData<N>);
+struct Bounded<N: Capacity>(usize, PhantomData<N>);
+
+impl<N: Capacity> Clone for Bounded<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N: Capacity> Copy for Bounded<N> {}
 
-impl<N: ArrayLength> PartialEq for Bounded<N> {
+impl<N: Capacity> PartialEq for Bounded<N> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
     }
 }
 
-impl<N: ArrayLength> Deref for Bounded<N> {
+impl<N: Capacity> Deref for Bounded<N> {
     type Target = usize;
 
     #[inline]
@@ -61,12 +96,12 @@ impl<N: ArrayLength> Deref for Bounded<N> {
     }
 }
 
-impl<N: ArrayLength> Bounded<N> {
+impl<N: Capacity> Bounded<N> {
     const ZERO: Self = Self(0, PhantomData);
 
     #[inline]
     const fn inc(mut self) -> Result<Self, Self> {
-        if self.0 == N::USIZE {
+        if self.0 == N::CAPACITY {
             Err(self)
         } else {
             self.0 += 1;
@@ -84,7 +119,7 @@ impl<N: ArrayLength> Bounded<N> {
     }
 }
 
-impl<N: ArrayLength> Add<Bounded<N>> for Wrapping<N> {
+impl<N: Capacity> Add<Bounded<N>> for Wrapping<N> {
     type Output = Self;
 
     #[inline]
@@ -94,42 +129,53 @@ impl<N: ArrayLength> Add<Bounded<N>> for Wrapping<N> {
     }
 }
 
-impl<N: ArrayLength> AddAssign<Bounded<N>> for Wrapping<N> {
+impl<N: Capacity> AddAssign<Bounded<N>> for Wrapping<N> {
     #[inline]
     fn add_assign(&mut self, rhs: Bounded<N>) {
+        if N::CAPACITY == 0 {
+            // the only possible offset is `0`
+            return;
+        }
+
         let (mut sum, ov) = self.0.overflowing_add(rhs.0);
-        if ov || sum >= N::USIZE {
-            sum = sum.wrapping_sub(N::USIZE);
+        if ov || sum >= N::CAPACITY {
+            sum = sum.wrapping_sub(N::CAPACITY);
         }
 
-        debug_assert!(sum < N::USIZE);
+        debug_assert!(sum < N::CAPACITY);
 
         self.0 = sum;
     }
 }
 
-pub(crate) struct Dequeue<T, N: ArrayLength> {
-    data: GenericArray<MaybeUninit<T>, N>,
+/// Fixed-capacity double-ended queue, backed by a ring buffer.
+///
+/// Capacity is set by the `N` type parameter, no allocations are performed.
+///
+/// Zero capacity is allowed: such a dequeue is always both empty and full, so it rejects every push, and evicts every overwriting push right away.
+/// Zero-sized elements are stored just like any other, taking no space.
+pub struct Dequeue<T, N: Capacity> {
+    data: N::Storage<T>,
     start: Wrapping<N>,
     len: Bounded<N>,
 }
 
-impl<T: Debug, N: ArrayLength> Debug for Dequeue<T, N> {
+impl<T: Debug, N: Capacity> Debug for Dequeue<T, N> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Dequeue")
-            .field("data", &self.data)
+            .field("data", &self.data.as_ref())
             .field("start", &*self.start)
             .field("len", &*self.len)
             .finish()
     }
 }
 
-impl<T: Clone, N: ArrayLength> Clone for Dequeue<T, N> {
+impl<T: Clone, N: Capacity> Clone for Dequeue<T, N> {
     fn clone(&self) -> Self {
-        let (slice1, slice2) = self.slices();
-        let mut data = GenericArray::uninit();
+        let (slice1, slice2) = self.as_slices();
+        let mut data = Self::uninit_storage();
 
-        let mut data_iter = data.iter_mut();
+        let mut data_iter = data.as_mut().iter_mut();
         for (src, dst) in slice1.iter().zip(&mut data_iter) {
             dst.write(src.clone());
         }
@@ -145,14 +191,23 @@ impl<T: Clone, N: ArrayLength> Clone for Dequeue<T, N> {
     }
 }
 
+/// Outcome of a push into a [`Dequeue`].
 #[must_use = "Contains information on whether the push is actually successful"]
-pub(crate) enum PushStatus<T> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PushStatus<T> {
+    /// Element was stored in the dequeue.
     Success,
+    /// Dequeue is full, element is handed back.
     Rejected(T),
 }
 
 impl<T> PushStatus<T> {
-    pub(crate) fn assert(self) {
+    /// Panics, if the push was rejected.
+    ///
+    /// See [`PushStatus::into_result`] for a non-panicking alternative.
+    #[cfg(any(test, not(feature = "no-panic")))]
+    #[inline]
+    pub fn assert(self) {
         match self {
             PushStatus::Success => {}
             PushStatus::Rejected(_) => {
@@ -160,14 +215,83 @@ impl<T> PushStatus<T> {
             }
         }
     }
+
+    #[inline]
+    pub const fn is_success(&self) -> bool {
+        matches!(self, PushStatus::Success)
+    }
+
+    #[inline]
+    pub const fn is_rejected(&self) -> bool {
+        matches!(self, PushStatus::Rejected(_))
+    }
+
+    /// Converts into a `Result`, with rejected element as an error.
+    #[inline]
+    pub fn into_result(self) -> Result<(), T> {
+        match self {
+            PushStatus::Success => Ok(()),
+            PushStatus::Rejected(item) => Err(item),
+        }
+    }
+}
+
+/// Operation requires more free space, than [`Dequeue`] has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CapacityError {
+    /// Number of elements that were attempted to be stored.
+    pub required: usize,
+    /// Number of free positions in the dequeue.
+    pub available: usize,
+}
+
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Dequeue out of capacity: {} elements required, but only {} available",
+            self.required, self.available
+        )
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// Part of an iterator, that did not fit into a [`Dequeue`], see [`Dequeue::try_extend`].
+#[derive(Debug, Clone)]
+pub struct Leftovers<T, I> {
+    /// Element, that was rejected due to dequeue being full.
+    pub rejected: T,
+    /// Rest of the iterator, not yet polled.
+    pub rest: I,
+}
+
+impl<T, I: Iterator<Item = T>> IntoIterator for Leftovers<T, I> {
+    type Item = T;
+    type IntoIter = core::iter::Chain<core::iter::Once<T>, I>;
+
+    /// Iterates over all of the elements that did not fit, starting from the rejected one.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::once(self.rejected).chain(self.rest)
+    }
 }
 
 // WARN: make use of `const` on mutating method, once `GenericArray` allows it
-impl<T, N: ArrayLength> Dequeue<T, N> {
+impl<T, N: Capacity> Dequeue<T, N> {
+    #[inline]
+    #[allow(clippy::uninit_assumed_init)] // `Capacity` is sealed, all storages are arrays of `MaybeUninit`
+    const fn uninit_storage() -> N::Storage<T> {
+        // SAFETY:
+        // Storage is an array of `MaybeUninit`, so it does not require initialization
+        unsafe { MaybeUninit::<N::Storage<T>>::uninit().assume_init() }
+    }
+
+    /// Creates an empty dequeue.
     #[inline]
-    pub(crate) const fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
-            data: GenericArray::uninit(),
+            data: Self::uninit_storage(),
             len: Bounded::ZERO,
             start: Wrapping::ZERO,
         }
@@ -175,10 +299,11 @@ impl<T, N: ArrayLength> Dequeue<T, N> {
 
     #[inline]
     fn write_at(&mut self, pos: Bounded<N>, item: T) {
-        self.data[*(self.start + pos)].write(item);
+        self.data.as_mut()[*(self.start + pos)].write(item);
     }
 
-    pub(crate) fn push_back(&mut self, item: T) -> PushStatus<T> {
+    /// Appends an element to the back, rejecting it if the dequeue is full.
+    pub fn push_back(&mut self, item: T) -> PushStatus<T> {
         match self.len.inc() {
             Ok(incremented) => {
                 // there is more space in the array
@@ -195,7 +320,8 @@ impl<T, N: ArrayLength> Dequeue<T, N> {
         }
     }
 
-    pub(crate) fn push_front(&mut self, item: T) -> PushStatus<T> {
+    /// Prepends an element to the front, rejecting it if the dequeue is full.
+    pub fn push_front(&mut self, item: T) -> PushStatus<T> {
         match self.len.inc() {
             Ok(incremented) => {
                 // there is more space in the array
@@ -216,35 +342,56 @@ impl<T, N: ArrayLength> Dequeue<T, N> {
     }
 
     #[inline]
-    unsafe fn overwrite_at(&mut self, pos: Bounded<N>, item: T) {
+    unsafe fn replace_at(&mut self, pos: Bounded<N>, item: T) -> T {
         let pos = *(self.start + pos);
-        self.data[pos].assume_init_drop();
-        self.data[pos].write(item);
+        core::mem::replace(&mut self.data.as_mut()[pos], MaybeUninit::new(item)).assume_init()
+    }
+
+    /// Appends an element to the back, dropping the front element if the dequeue is full.
+    #[inline]
+    pub fn push_back_overwrite(&mut self, item: T) {
+        drop(self.push_back_evict(item));
     }
 
-    pub(crate) fn push_back_overwrite(&mut self, item: T) {
+    /// Prepends an element to the front, dropping the back element if the dequeue is full.
+    #[inline]
+    pub fn push_front_overwrite(&mut self, item: T) {
+        drop(self.push_front_evict(item));
+    }
+
+    /// Appends an element to the back, evicting the front element if the dequeue is full.
+    ///
+    /// Returns the evicted element, if any. With zero capacity, that's always the pushed element itself.
+    pub fn push_back_evict(&mut self, item: T) -> Option<T> {
         match self.len.inc() {
             Ok(incremented) => {
                 // there is more space in the array
 
                 self.write_at(self.len, item);
                 self.len = incremented;
+                None
+            }
+            Err(_unchanged) if N::CAPACITY == 0 => {
+                // no space at all - pushed element is evicted right away
+                Some(item)
             }
             Err(_unchanged) => {
-                // no more space - overwrite
+                // no more space - evict
                 //
-                // overwrite to 0 and move the start
+                // replace at 0 and move the start
 
                 // SAFETY: array is full, so all positions should contain valid data
-                unsafe {
-                    self.overwrite_at(Bounded::ZERO, item);
-                }
+                let evicted = unsafe { self.replace_at(Bounded::ZERO, item) };
                 self.start.inc();
+                Some(evicted)
             }
         }
     }
 
-    pub(crate) fn push_front_overwrite(&mut self, item: T) {
+    /// Prepends an element to the front, evicting the back element if the dequeue is full.
+    ///
+    /// Returns the evicted element, if any. With zero capacity, that's always the pushed element itself.
+    pub fn push_front_evict(&mut self, item: T) -> Option<T> {
         match self.len.inc() {
             Ok(incremented) => {
                 // there is more space in the array
@@ -254,27 +401,30 @@ impl<T, N: ArrayLength> Dequeue<T, N> {
                 self.start.dec();
                 self.write_at(Bounded::ZERO, item);
                 self.len = incremented;
+                None
+            }
+            Err(_unchanged) if N::CAPACITY == 0 => {
+                // no space at all - pushed element is evicted right away
+                Some(item)
             }
             Err(_unchanged) => {
-                // no more space - overwrite
+                // no more space - evict
                 //
-                // move starting position and overwrite to 0
+                // move starting position and replace at 0
 
                 self.start.dec();
                 // SAFETY: array is full, so all positions should contain valid data
-                unsafe {
-                    self.overwrite_at(Bounded::ZERO, item);
-                }
+                Some(unsafe { self.replace_at(Bounded::ZERO, item) })
             }
         }
     }
 
     #[inline]
     unsafe fn take_at(&mut self, pos: Bounded<N>) -> T {
-        self.data[*(self.start + pos)].assume_init_read()
+        self.data.as_ref()[*(self.start + pos)].assume_init_read()
     }
 
-    pub(crate) fn pop_back(&mut self) -> Option<T> {
+    pub fn pop_back(&mut self) -> Option<T> {
         match self.len.dec() {
             Ok(len_m1) => {
                 // take from logical `len-1`
@@ -287,7 +437,7 @@ impl<T, N: ArrayLength> Dequeue<T, N> {
         }
     }
 
-    pub(crate) fn pop_front(&mut self) -> Option<T> {
+    pub fn pop_front(&mut self) -> Option<T> {
         match self.len.dec() {
             Ok(len_m1) => {
                 // take from logical `0`, then move the start
@@ -304,10 +454,10 @@ impl<T, N: ArrayLength> Dequeue<T, N> {
 
     #[inline]
     unsafe fn read_at(&self, pos: Bounded<N>) -> &T {
-        self.data[*(self.start + pos)].assume_init_ref()
+        self.data.as_ref()[*(self.start + pos)].assume_init_ref()
     }
 
-    pub(crate) fn get(&self, i: usize) -> Option<&T> {
+    pub fn get(&self, i: usize) -> Option<&T> {
         if i < *self.len {
             // SAFETY:
             // Logical positions from `0` to `len-1` contain valid elements. Above condition checks that index is bounded by `len`.
@@ -321,10 +471,10 @@ impl<T, N: ArrayLength> Dequeue<T, N> {
 
     #[inline]
     unsafe fn read_at_mut(&mut self, pos: Bounded<N>) -> &mut T {
-        self.data[*(self.start + pos)].assume_init_mut()
+        self.data.as_mut()[*(self.start + pos)].assume_init_mut()
     }
 
-    pub(crate) fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
         if i < *self.len {
             // SAFETY:
             // Logical positions from `0` to `len-1` contain valid elements. Above condition checks that index is bounded by `len`.
@@ -336,137 +486,983 @@ impl<T, N: ArrayLength> Dequeue<T, N> {
         }
     }
 
+    /// Same as [`Dequeue::get`], but reports the length on failure.
+    #[inline]
+    pub fn try_get(&self, index: usize) -> Result<&T, Error> {
+        let len = *self.len;
+        self.get(index).ok_or(Error::IndexOutOfRange { index, len })
+    }
+
+    /// Same as [`Dequeue::get_mut`], but reports the length on failure.
+    #[inline]
+    pub fn try_get_mut(&mut self, index: usize) -> Result<&mut T, Error> {
+        let len = *self.len;
+        self.get_mut(index)
+            .ok_or(Error::IndexOutOfRange { index, len })
+    }
+
+    /// Returns mutable references to elements at several logical positions at once.
+    ///
+    /// Returns `None`, if any of the indices is out of bounds, or indices are not distinct.
+    pub fn get_disjoint_mut<const K: usize>(&mut self, indices: [usize; K]) -> Option<[&mut T; K]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= *self.len || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        let start = self.start;
+        let data = self.data.as_mut().as_mut_ptr();
+        // SAFETY:
+        // All of the indices are bounded by `len`, so they point to valid data.
+        // Indices are distinct, so references do not alias.
+        Some(indices.map(|index| unsafe {
+            let pos = *(start + Bounded(index, PhantomData));
+            (*data.add(pos)).assume_init_mut()
+        }))
+    }
+
     #[inline]
-    pub(crate) fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         *self.len
     }
 
     #[inline]
-    pub(crate) fn is_empty(&self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.len == Bounded::ZERO
     }
 
-    fn slices(&self) -> (&[T], &[T]) {
+    /// Maximum number of elements this dequeue can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N::CAPACITY
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        *self.len == N::CAPACITY
+    }
+
+    /// Physical ranges of the `data` array, that are free, in the order they would be filled by pushing to the back.
+    fn free_ranges(&self) -> (Range<usize>, Range<usize>) {
+        if self.is_full() {
+            return (0..0, 0..0);
+        }
+        let end = *(self.start + self.len);
+
+        if end >= *self.start {
+            // free positions are spread from the end to the physical end, and from physical start to the start
+            (end..N::CAPACITY, 0..*self.start)
+        } else {
+            // free positions are between the end and the start
+            (end..*self.start, 0..0)
+        }
+    }
+
+    /// Physical ranges of the `data` array, containing the logical elements in order.
+    ///
+    /// If last position comes physically after the start, all elements are in the first range, and the second one is empty.
+    ///
+    /// If last position comes physically before the start, elements are spread from the start to the physical end, and from physical start to logical end.
+    fn physical_ranges(&self) -> (Range<usize>, Range<usize>) {
         let Ok(len_m1) = self.len.dec() else {
             // vec is empty
-            return (&[], &[]);
+            return (0..0, 0..0);
         };
         let last_position = self.start + len_m1;
 
+        if *last_position >= *self.start {
+            // has a single slice
+            (*self.start..*last_position + 1, 0..0)
+        } else {
+            // has two slices
+            (*self.start..N::CAPACITY, 0..*last_position + 1)
+        }
+    }
+
+    /// Bitwise moves `count` elements from logical position `src` to logical position `dst`.
+    ///
+    /// # Safety
+    /// Source positions must contain valid data, and all of the positions must be less than `N`.
+    /// Destination positions are overwritten without dropping, and source positions not overlapped by destination should be considered uninitialized afterwards.
+    unsafe fn move_within(&mut self, src: usize, dst: usize, count: usize) {
+        let mut move_one = |i: usize| {
+            let from = *(self.start + Bounded(src + i, PhantomData));
+            let to = *(self.start + Bounded(dst + i, PhantomData));
+            self.data.as_mut()[to] = core::ptr::read(&self.data.as_ref()[from]);
+        };
+
+        if dst > src {
+            // moving forward - start from the end, to not overwrite the source
+            (0..count).rev().for_each(&mut move_one);
+        } else {
+            (0..count).for_each(&mut move_one);
+        }
+    }
+
+    /// Returns a pair of slices, containing the elements in logical order.
+    ///
+    /// Second slice is empty, unless elements wrap around the end of the underlying array.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.physical_ranges();
+
+        #[cfg(debug_assertions)]
+        {
+            for i in first.clone().chain(second.clone()) {
+                // SAFETY: see below
+                unsafe {
+                    self.data.as_ref()[i].assume_init_ref();
+                }
+            }
+        }
+
         // SAFETY:
-        // Last element is contained on the logical position `len-1`
-        //
-        // If last position comes physically after the start, all elements are in a single slice
-        //
-        // If last position comes physically before the start, elements are spread from the start to the physical end, and from physical start to logical end
+        // Physical ranges contain exactly the logical positions `0` to `len-1`, that always contain valid data
         unsafe {
             use core::ptr::from_ref;
-            if *last_position >= *self.start {
-                // has a single slice
-                #[cfg(debug_assertions)]
-                {
-                    for i in *self.start..=*last_position {
-                        self.data[i].assume_init_ref();
-                    }
-                }
-                (
-                    &*(from_ref(&self.data[*self.start..=*last_position]) as *const [T]),
-                    &[],
-                )
+            (
+                &*(from_ref(&self.data.as_ref()[first]) as *const [T]),
+                &*(from_ref(&self.data.as_ref()[second]) as *const [T]),
+            )
+        }
+    }
+
+    /// Returns a pair of mutable slices, containing the elements in logical order.
+    ///
+    /// Second slice is empty, unless elements wrap around the end of the underlying array.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.physical_ranges();
+
+        // second range always comes physically before the first one
+        let (head, tail) = self.data.as_mut().split_at_mut(first.start);
+        let first = &mut tail[..first.len()];
+        let second = &mut head[second];
+
+        // SAFETY:
+        // Physical ranges contain exactly the logical positions `0` to `len-1`, that always contain valid data
+        unsafe {
+            use core::ptr::from_mut;
+            (
+                &mut *(from_mut(first) as *mut [T]),
+                &mut *(from_mut(second) as *mut [T]),
+            )
+        }
+    }
+
+    /// Inserts an element at logical position `index`, shifting whichever side of the dequeue is shorter.
+    ///
+    /// Rejects the element, if the dequeue is full.
+    ///
+    /// # Panics
+    /// If `index` is greater than the length. See [`Dequeue::try_insert`] for a non-panicking alternative.
+    #[cfg(any(test, not(feature = "no-panic")))]
+    pub fn insert(&mut self, index: usize, item: T) -> PushStatus<T> {
+        match self.try_insert(index, item) {
+            Ok(()) => PushStatus::Success,
+            Err((Error::Capacity(_), item)) => PushStatus::Rejected(item),
+            Err((error, _)) => panic!("{error}"),
+        }
+    }
+
+    /// Inserts an element at logical position `index`, shifting whichever side of the dequeue is shorter.
+    ///
+    /// Fails, if `index` is greater than the length, or the dequeue is full, handing the element back.
+    pub fn try_insert(&mut self, index: usize, item: T) -> Result<(), (Error, T)> {
+        let len = *self.len;
+        if index > len {
+            return Err((Error::IndexOutOfRange { index, len }, item));
+        }
+        let Ok(incremented) = self.len.inc() else {
+            // no more space - reject
+            let error = CapacityError {
+                required: 1,
+                available: 0,
+            };
+            return Err((error.into(), item));
+        };
+
+        // SAFETY:
+        // Dequeue is not full, so there is a free position both before the start and after the end.
+        // Moved positions contain valid data, and the position at `index` is free after the move.
+        unsafe {
+            if index < len - index {
+                // move the head one position back
+                self.start.dec();
+                self.move_within(1, 0, index);
             } else {
-                // has two slices
-                #[cfg(debug_assertions)]
-                {
-                    for i in *self.start..N::USIZE {
-                        self.data[i].assume_init_ref();
-                    }
-                }
-                #[cfg(debug_assertions)]
-                {
-                    for i in 0..=*last_position {
-                        self.data[i].assume_init_ref();
-                    }
-                }
-                (
-                    &*(from_ref(&self.data[*self.start..]) as *const [T]),
-                    &*(from_ref(&self.data[..=*last_position]) as *const [T]),
-                )
+                // move the tail one position forward
+                self.move_within(index, index + 1, len - index);
             }
         }
+        self.write_at(Bounded(index, PhantomData), item);
+        self.len = incremented;
+
+        Ok(())
     }
 
-    pub(crate) fn clear(&mut self) {
-        let Ok(len_m1) = self.len.dec() else {
-            // vec is empty, nothing to do
-            return;
+    /// Removes and returns an element at logical position `index`, shifting whichever side of the dequeue is shorter.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let len = *self.len;
+        if index >= len {
+            return None;
+        }
+
+        // SAFETY:
+        // Logical positions from `0` to `len-1` contain valid data, and index is bounded by `len`.
+        // After the element is taken, its position is free to be overwritten.
+        let item = unsafe {
+            let item = self.take_at(Bounded(index, PhantomData));
+            if index < len - 1 - index {
+                // move the head one position forward
+                self.move_within(0, 1, index);
+                self.start.inc();
+            } else {
+                // move the tail one position back
+                self.move_within(index + 1, index, len - 1 - index);
+            }
+            item
         };
-        let last_position = self.start + len_m1;
+        self.len = Bounded(len - 1, PhantomData);
+
+        Some(item)
+    }
+
+    /// Swaps elements at logical positions `i` and `j`.
+    ///
+    /// # Panics
+    /// If either of the indices is out of bounds. See [`Dequeue::try_swap`] for a non-panicking alternative.
+    #[cfg(any(test, not(feature = "no-panic")))]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        if let Err(error) = self.try_swap(i, j) {
+            panic!("{error}");
+        }
+    }
+
+    /// Swaps elements at logical positions `i` and `j`.
+    ///
+    /// Fails, if either of the indices is out of bounds.
+    pub fn try_swap(&mut self, i: usize, j: usize) -> Result<(), Error> {
+        let len = *self.len;
+        for index in [i, j] {
+            if index >= len {
+                return Err(Error::IndexOutOfRange { index, len });
+            }
+        }
+        self.swap_unchecked(i, j);
+        Ok(())
+    }
+
+    /// Caller must ensure, that both indices are less than the length.
+    #[inline]
+    fn swap_unchecked(&mut self, i: usize, j: usize) {
+        let i = *(self.start + Bounded(i, PhantomData));
+        let j = *(self.start + Bounded(j, PhantomData));
+        self.data.as_mut().swap(i, j);
+    }
+
+    /// Removes an element at logical position `index`, replacing it with the first element.
+    ///
+    /// Does not preserve the order, but is O(1).
+    pub fn swap_remove_front(&mut self, index: usize) -> Option<T> {
+        if index >= *self.len {
+            return None;
+        }
+        self.swap_unchecked(index, 0);
+        self.pop_front()
+    }
+
+    /// Removes an element at logical position `index`, replacing it with the last element.
+    ///
+    /// Does not preserve the order, but is O(1).
+    pub fn swap_remove_back(&mut self, index: usize) -> Option<T> {
+        let len = *self.len;
+        if index >= len {
+            return None;
+        }
+        self.swap_unchecked(index, len - 1);
+        self.pop_back()
+    }
+
+    /// Rotates the dequeue `n` places to the left, so that the element at `n` becomes the first one.
+    ///
+    /// # Panics
+    /// If `n` is greater than the length. See [`Dequeue::try_rotate_left`] for a non-panicking alternative.
+    #[cfg(any(test, not(feature = "no-panic")))]
+    pub fn rotate_left(&mut self, n: usize) {
+        if let Err(error) = self.try_rotate_left(n) {
+            panic!("{error}");
+        }
+    }
+
+    /// Rotates the dequeue `n` places to the left, so that the element at `n` becomes the first one.
+    ///
+    /// Fails, if `n` is greater than the length.
+    pub fn try_rotate_left(&mut self, n: usize) -> Result<(), Error> {
+        let len = *self.len;
+        if n > len {
+            return Err(Error::IndexOutOfRange { index: n, len });
+        }
+        if n <= len - n {
+            // SAFETY: `n` is bounded by `len`
+            unsafe { self.rotate_left_unchecked(n) }
+        } else {
+            // SAFETY: `len - n` is bounded by `len`
+            unsafe { self.rotate_right_unchecked(len - n) }
+        }
+        Ok(())
+    }
+
+    /// Rotates the dequeue `n` places to the right, so that the last `n` elements become the first ones.
+    ///
+    /// # Panics
+    /// If `n` is greater than the length. See [`Dequeue::try_rotate_right`] for a non-panicking alternative.
+    #[cfg(any(test, not(feature = "no-panic")))]
+    pub fn rotate_right(&mut self, n: usize) {
+        if let Err(error) = self.try_rotate_right(n) {
+            panic!("{error}");
+        }
+    }
+
+    /// Rotates the dequeue `n` places to the right, so that the last `n` elements become the first ones.
+    ///
+    /// Fails, if `n` is greater than the length.
+    pub fn try_rotate_right(&mut self, n: usize) -> Result<(), Error> {
+        let len = *self.len;
+        if n > len {
+            return Err(Error::IndexOutOfRange { index: n, len });
+        }
+        if n <= len - n {
+            // SAFETY: `n` is bounded by `len`
+            unsafe { self.rotate_right_unchecked(n) }
+        } else {
+            // SAFETY: `len - n` is bounded by `len`
+            unsafe { self.rotate_left_unchecked(len - n) }
+        }
+        Ok(())
+    }
+
+    /// # Safety
+    /// `n` must not be greater than the length.
+    unsafe fn rotate_left_unchecked(&mut self, n: usize) {
+        if self.is_full() {
+            // every position is occupied, moving the start is enough
+            self.start += Bounded(n, PhantomData);
+            return;
+        }
+
+        for _ in 0..n {
+            // move the first element right after the last one
+            //
+            // position after the last one is free, since dequeue is not full
+            let front = *self.start;
+            let back = *(self.start + self.len);
+            self.data.as_mut()[back] = core::ptr::read(&self.data.as_ref()[front]);
+            self.start.inc();
+        }
+    }
 
+    /// # Safety
+    /// `n` must not be greater than the length.
+    unsafe fn rotate_right_unchecked(&mut self, n: usize) {
+        if self.is_full() {
+            // every position is occupied, moving the start is enough
+            self.start += Bounded(N::CAPACITY - n, PhantomData);
+            return;
+        }
+
+        for _ in 0..n {
+            // move the last element right before the first one
+            //
+            // position before the first one is free, since dequeue is not full
+            self.start.dec();
+            let front = *self.start;
+            let back = *(self.start + self.len);
+            self.data.as_mut()[front] = core::ptr::read(&self.data.as_ref()[back]);
+        }
+    }
+
+    /// Copies as many elements from the slice to the back, as there's space for.
+    ///
+    /// Returns number of copied elements.
+    pub fn push_back_from_slice(&mut self, items: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        let (first, second) = self.free_ranges();
+        let mut copied = 0;
+        for range in [first, second] {
+            let count = range.len().min(items.len() - copied);
+            let dst = &mut self.data.as_mut()[range.start..range.start + count];
+            // SAFETY:
+            // Destination is a free part of the array, that does not overlap with the source, and is at least `count` elements long.
+            // Copied elements are `Copy`, so they may be duplicated bitwise.
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    items[copied..].as_ptr(),
+                    dst.as_mut_ptr().cast::<T>(),
+                    count,
+                );
+            }
+            copied += count;
+        }
+        self.len = Bounded(*self.len + copied, PhantomData);
+
+        copied
+    }
+
+    /// Moves as many elements from the front to the slice, as it can hold.
+    ///
+    /// Returns number of moved elements.
+    pub fn pop_front_into_slice(&mut self, out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let copied = out.len().min(*self.len);
         // SAFETY:
-        // Last element is contained on the logical position `len-1`
-        //
-        // If last position comes physically after the start, all elements are in a single slice
-        //
-        // If last position comes physically before the start, elements are spread from the start to the physical end, and from physical start to logical end
+        // Number of moved elements is bounded by the length, and slice has space for them.
+        // Elements are `Copy`, so overwriting them without a drop is fine.
+        unsafe { self.move_front_into(out.as_mut_ptr(), copied) };
+
+        copied
+    }
+
+    /// Removes `K` elements from the front, returning them as an array.
+    ///
+    /// Returns `None`, if there are less than `K` elements.
+    pub(crate) fn pop_front_array<const K: usize>(&mut self) -> Option<[T; K]> {
+        if *self.len < K {
+            return None;
+        }
+        let mut array = MaybeUninit::<[T; K]>::uninit();
+        // SAFETY:
+        // Number of moved elements is bounded by the length, and array has space for exactly that many
         unsafe {
-            if *last_position >= *self.start {
-                // has a single slice
-                for i in *self.start..=*last_position {
-                    self.data[i].assume_init_drop();
+            self.move_front_into(array.as_mut_ptr().cast::<T>(), K);
+            Some(array.assume_init())
+        }
+    }
+
+    /// Bitwise moves `count` elements from the front to `dst`, removing them from the dequeue.
+    ///
+    /// # Safety
+    /// `count` must not be greater than the length, and `dst` must be valid for writing `count` elements.
+    /// Whatever was stored at `dst` is overwritten without dropping.
+    unsafe fn move_front_into(&mut self, dst: *mut T, count: usize) {
+        let (first, second) = self.as_slices();
+        let from_first = count.min(first.len());
+        core::ptr::copy_nonoverlapping(first.as_ptr(), dst, from_first);
+        core::ptr::copy_nonoverlapping(second.as_ptr(), dst.add(from_first), count - from_first);
+
+        self.start += Bounded(count, PhantomData);
+        self.len = Bounded(*self.len - count, PhantomData);
+    }
+
+    /// Appends elements from the iterator to the back, until the dequeue is full.
+    ///
+    /// If iterator yields an element that does not fit, it is returned along with the rest of the iterator.
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), Leftovers<T, I::IntoIter>> {
+        let mut iter = iter.into_iter();
+        while let Some(item) = iter.next() {
+            if let PushStatus::Rejected(rejected) = self.push_back(item) {
+                return Err(Leftovers {
+                    rejected,
+                    rest: iter,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends all elements from the iterator to the back, dropping elements from the front once the dequeue is full.
+    ///
+    /// Effectively, dequeue ends up with the last `N` elements.
+    pub fn extend_overwrite<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back_overwrite(item);
+        }
+    }
+
+    /// Collects elements into a new dequeue.
+    ///
+    /// Fails, if iterator yields more than `N` elements.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Error> {
+        let mut dequeue = Self::new();
+        match dequeue.try_extend(iter) {
+            Ok(()) => Ok(dequeue),
+            Err(_) => Err(Error::Capacity(CapacityError {
+                required: N::CAPACITY + 1,
+                available: N::CAPACITY,
+            })),
+        }
+    }
+
+    /// Retains only the elements specified by the predicate, preserving their order.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|item| f(item));
+    }
+
+    /// Retains only the elements specified by the predicate, preserving their order.
+    ///
+    /// Predicate is allowed to mutate the elements.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        self.retain_with_prev(|item, _| f(item));
+    }
+
+    /// Removes consecutive elements, that are considered to be the same by `same_bucket`, keeping the first one of each run.
+    ///
+    /// `same_bucket` receives the element in question, and the last retained element before it.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        self.retain_with_prev(|item, prev| prev.is_none_or(|prev| !same_bucket(item, prev)));
+    }
+
+    /// Removes consecutive elements, that resolve to the same key.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive repeated elements.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// In-place filtering, with predicate receiving the element in question and the last retained element.
+    ///
+    /// If predicate (or some element's drop) panics, not yet processed elements are preserved.
+    fn retain_with_prev<F: FnMut(&mut T, Option<&mut T>) -> bool>(&mut self, mut f: F) {
+        let len = *self.len;
+        let mut guard = RetainGuard {
+            dequeue: self,
+            processed: 0,
+            kept: 0,
+            len,
+        };
+
+        while guard.processed < guard.len {
+            let start = guard.dequeue.start;
+            let current = *(start + Bounded(guard.processed, PhantomData));
+            // SAFETY:
+            // Unprocessed positions contain valid data, so do positions of the kept elements.
+            // These are always different positions, so references do not alias.
+            let (item, prev) = unsafe {
+                let data = guard.dequeue.data.as_mut().as_mut_ptr();
+                let item = &mut *(*data.add(current)).as_mut_ptr();
+                let prev = match guard.kept.checked_sub(1) {
+                    Some(last_kept) => {
+                        let last_kept = *(start + Bounded(last_kept, PhantomData));
+                        Some(&mut *(*data.add(last_kept)).as_mut_ptr())
+                    }
+                    None => None,
+                };
+                (item, prev)
+            };
+
+            if f(item, prev) {
+                // SAFETY:
+                // Positions between kept and processed are free, current position is considered free after the move
+                unsafe {
+                    guard.dequeue.move_within(guard.processed, guard.kept, 1);
                 }
+                guard.kept += 1;
+                guard.processed += 1;
             } else {
-                // has two slices
+                // mark as processed first, so that panicking drop does not cause a double drop
+                guard.processed += 1;
+                // SAFETY: current position contains valid data, that is no longer considered as such
+                unsafe { core::ptr::drop_in_place(item) };
+            }
+        }
+    }
 
-                for i in *self.start..N::USIZE {
-                    self.data[i].assume_init_drop();
-                }
-                for i in 0..=*last_position {
-                    self.data[i].assume_init_drop();
-                }
+    /// Shortens the dequeue to `len` elements, dropping the elements at the back.
+    ///
+    /// Does nothing, if dequeue is already short enough.
+    pub fn truncate_back(&mut self, len: usize) {
+        let orig_len = *self.len;
+        if len < orig_len {
+            Drain::new(self, len..orig_len);
+        }
+    }
+
+    /// Shortens the dequeue to `len` elements, dropping the elements at the front.
+    ///
+    /// Does nothing, if dequeue is already short enough.
+    pub fn truncate_front(&mut self, len: usize) {
+        if let Some(excess) = self.len().checked_sub(len) {
+            Drain::new(self, 0..excess);
+        }
+    }
+
+    /// Moves elements starting from logical position `at` into a new dequeue, possibly of different capacity.
+    ///
+    /// # Panics
+    /// If `at` is greater than the length, or moved elements do not fit into the new dequeue.
+    /// See [`Dequeue::try_split_off`] for a non-panicking alternative.
+    #[cfg(any(test, not(feature = "no-panic")))]
+    pub fn split_off<M: Capacity>(&mut self, at: usize) -> Dequeue<T, M> {
+        match self.try_split_off(at) {
+            Ok(other) => other,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Moves elements starting from logical position `at` into a new dequeue, possibly of different capacity.
+    ///
+    /// Fails, if `at` is greater than the length, or moved elements do not fit into the new dequeue.
+    /// Dequeue is not modified in that case.
+    pub fn try_split_off<M: Capacity>(&mut self, at: usize) -> Result<Dequeue<T, M>, Error> {
+        let len = *self.len;
+        let Some(moved) = len.checked_sub(at) else {
+            return Err(Error::IndexOutOfRange { index: at, len });
+        };
+        if moved > M::CAPACITY {
+            return Err(Error::Capacity(CapacityError {
+                required: moved,
+                available: M::CAPACITY,
+            }));
+        }
+
+        let mut other = Dequeue::<T, M>::new();
+        for (i, dst) in other.data.as_mut().iter_mut().take(moved).enumerate() {
+            // SAFETY:
+            // Logical positions from `at` to `len-1` contain valid data, and are no longer considered as such after this loop
+            dst.write(unsafe { self.take_at(Bounded(at + i, PhantomData)) });
+        }
+        other.len = Bounded(moved, PhantomData);
+        self.len = Bounded(at, PhantomData);
+
+        Ok(other)
+    }
+
+    /// Moves all of the elements from `other` to the back of this dequeue, leaving `other` empty.
+    ///
+    /// If elements do not fit, neither of dequeues is modified.
+    pub fn append<M: Capacity>(&mut self, other: &mut Dequeue<T, M>) -> Result<(), CapacityError> {
+        let len = *self.len;
+        let moved = *other.len;
+        let available = N::CAPACITY - len;
+        if moved > available {
+            return Err(CapacityError {
+                required: moved,
+                available,
+            });
+        }
+
+        for i in 0..moved {
+            // SAFETY:
+            // Logical positions from `0` to `len-1` of `other` contain valid data, and are no longer considered as such after this loop.
+            // Destination positions are free, since there's enough space for all of the elements.
+            unsafe {
+                let item = other.take_at(Bounded(i, PhantomData));
+                self.write_at(Bounded(len + i, PhantomData), item);
             }
         }
+        other.start = Wrapping::ZERO;
+        other.len = Bounded::ZERO;
+        self.len = Bounded(len + moved, PhantomData);
+
+        Ok(())
+    }
+
+    /// Rotates the underlying array, so that all of the elements are stored in a single slice, which is returned.
+    ///
+    /// After this call, [`Dequeue::as_slices`] returns an empty second slice, until the front is pushed to, or the back wraps around.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if *self.start != 0 {
+            // rotation moves `MaybeUninit` values bitwise, nothing is dropped or duplicated
+            self.data.as_mut().rotate_left(*self.start);
+            self.start = Wrapping::ZERO;
+        }
+
+        let (contiguous, rest) = self.as_mut_slices();
+        debug_assert!(rest.is_empty());
+        contiguous
+    }
+
+    /// Returns a front-to-back iterator over references to the elements.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (first, second) = self.as_slices();
+        Iter::new(first, second)
+    }
+
+    /// Returns a front-to-back iterator over mutable references to the elements.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (first, second) = self.as_mut_slices();
+        IterMut::new(first, second)
+    }
+
+    /// Removes the specified logical range from the dequeue, returning removed elements as an iterator.
+    ///
+    /// Elements not consumed by the iterator are dropped, once it is dropped.
+    ///
+    /// # Panics
+    /// If the range is out of bounds, or its start is after the end. See [`Dequeue::try_drain`] for a non-panicking alternative.
+    #[cfg(any(test, not(feature = "no-panic")))]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, N> {
+        match self.try_drain(range) {
+            Ok(drain) => drain,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Removes the specified logical range from the dequeue, returning removed elements as an iterator.
+    ///
+    /// Fails, if the range is out of bounds, or its start is after the end.
+    pub fn try_drain<R: RangeBounds<usize>>(&mut self, range: R) -> Result<Drain<'_, T, N>, Error> {
+        let range = logical_range(range, *self.len)?;
+        Ok(Drain::new(self, range))
+    }
 
+    /// Drops all of the stored elements.
+    pub fn clear(&mut self) {
+        let (first, second) = self.as_mut_slices();
+        let (first, second) = (first as *mut [T], second as *mut [T]);
+
+        // mark the dequeue empty first, so that a panicking drop leaks elements instead of dropping them twice
         self.start = Wrapping::ZERO;
         self.len = Bounded::ZERO;
+
+        // SAFETY:
+        // Slices contain all of the valid elements, and dequeue no longer considers them initialized
+        unsafe {
+            core::ptr::drop_in_place(first);
+            core::ptr::drop_in_place(second);
+        }
+    }
+}
+/// Restores [`Dequeue`] consistency during, and after the in-place filtering.
+///
+/// Logical positions before `kept` contain retained elements, positions from `kept` to `processed` are free, and positions from `processed` to `len` are not yet processed.
+struct RetainGuard<'d, T, N: Capacity> {
+    dequeue: &'d mut Dequeue<T, N>,
+    processed: usize,
+    kept: usize,
+    len: usize,
+}
+
+impl<T, N: Capacity> Drop for RetainGuard<'_, T, N> {
+    fn drop(&mut self) {
+        let unprocessed = self.len - self.processed;
+        // SAFETY: unprocessed positions contain valid data, and are moved to the free positions
+        unsafe {
+            self.dequeue
+                .move_within(self.processed, self.kept, unprocessed);
+        }
+        self.dequeue.len = Bounded(self.kept + unprocessed, PhantomData);
+    }
+}
+
+/// Converts range bounds to a range of logical positions.
+///
+/// Range start is checked against the range end, and range end is checked against the length.
+fn logical_range<R: RangeBounds<usize>>(range: R, len: usize) -> Result<Range<usize>, Error> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        // overflowing start lies beyond any length
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if end > len {
+        return Err(Error::IndexOutOfRange { index: end, len });
+    }
+    if start > end {
+        return Err(Error::IndexOutOfRange {
+            index: start,
+            len: end,
+        });
     }
+    Ok(start..end)
 }
 
-impl<T, N: ArrayLength> Default for Dequeue<T, N> {
+impl<T, N: Capacity> Default for Dequeue<T, N> {
     #[inline]
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T, N: ArrayLength> Index<usize> for Dequeue<T, N> {
+/// # Panics
+/// If the index is out of bounds. See [`Dequeue::try_get`] for a non-panicking alternative.
+#[cfg(any(test, not(feature = "no-panic")))]
+impl<T, N: Capacity> core::ops::Index<usize> for Dequeue<T, N> {
     type Output = T;
 
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
-        if let Some(res) = self.get(index) {
-            res
-        } else {
-            panic!(
-                "Index out of bounds: index {index}, but length {}",
-                *self.len
-            );
+        match self.try_get(index) {
+            Ok(res) => res,
+            Err(error) => panic!("{error}"),
         }
     }
 }
 
-impl<T, N: ArrayLength> IndexMut<usize> for Dequeue<T, N> {
+/// # Panics
+/// If the index is out of bounds. See [`Dequeue::try_get_mut`] for a non-panicking alternative.
+#[cfg(any(test, not(feature = "no-panic")))]
+impl<T, N: Capacity> core::ops::IndexMut<usize> for Dequeue<T, N> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        let len = self.len;
-        if let Some(res) = self.get_mut(index) {
-            res
-        } else {
-            panic!("Index out of bounds: index {index}, but length {}", *len);
+        match self.try_get_mut(index) {
+            Ok(res) => res,
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
+
+/// Appends elements to the back.
+///
+/// # Panics
+/// If the dequeue runs out of capacity. See [`Dequeue::try_extend`] and [`Dequeue::extend_overwrite`] for non-panicking alternatives.
+#[cfg(any(test, not(feature = "no-panic")))]
+impl<T, N: Capacity> Extend<T> for Dequeue<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if self.try_extend(iter).is_err() {
+            panic!("Dequeue out of capacity, failed to extend");
+        }
+    }
+}
+
+/// Appends copied elements to the back.
+///
+/// # Panics
+/// If the dequeue runs out of capacity.
+#[cfg(any(test, not(feature = "no-panic")))]
+impl<'a, T: Copy + 'a, N: Capacity> Extend<&'a T> for Dequeue<T, N> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Collects elements into a new dequeue.
+///
+/// # Panics
+/// If iterator yields more than `N` elements. See [`Dequeue::try_from_iter`] for a non-panicking alternative.
+#[cfg(any(test, not(feature = "no-panic")))]
+impl<T, N: Capacity> FromIterator<T> for Dequeue<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        match Self::try_from_iter(iter) {
+            Ok(dequeue) => dequeue,
+            Err(error) => panic!("{error}"),
         }
     }
 }
 
-impl<T, N: ArrayLength> Drop for Dequeue<T, N> {
+/// Compares elements in logical order, regardless of how they are placed in the underlying array.
+impl<T: PartialEq<U>, U, N: Capacity, M: Capacity> PartialEq<Dequeue<U, M>> for Dequeue<T, N> {
+    #[inline]
+    fn eq(&self, other: &Dequeue<U, M>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, N: Capacity> Eq for Dequeue<T, N> {}
+
+impl<T: PartialEq<U>, U, N: Capacity> PartialEq<[U]> for Dequeue<T, N> {
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        let (first, second) = self.as_slices();
+        first.len() + second.len() == other.len() && {
+            let (other_first, other_second) = other.split_at(first.len());
+            first == other_first && second == other_second
+        }
+    }
+}
+
+impl<T: PartialEq<U>, U, N: Capacity> PartialEq<&[U]> for Dequeue<T, N> {
+    #[inline]
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, N: Capacity> PartialEq<&mut [U]> for Dequeue<T, N> {
+    #[inline]
+    fn eq(&self, other: &&mut [U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, N: Capacity, const K: usize> PartialEq<[U; K]> for Dequeue<T, N> {
+    #[inline]
+    fn eq(&self, other: &[U; K]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, N: Capacity, const K: usize> PartialEq<&[U; K]> for Dequeue<T, N> {
+    #[inline]
+    fn eq(&self, other: &&[U; K]) -> bool {
+        *self == other[..]
+    }
+}
+
+/// Compares elements lexicographically, in logical order.
+impl<T: PartialOrd, N: Capacity, M: Capacity> PartialOrd<Dequeue<T, M>> for Dequeue<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Dequeue<T, M>) -> Option<core::cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, N: Capacity> Ord for Dequeue<T, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes elements in logical order, so that equal dequeues have equal hashes.
+impl<T: Hash, N: Capacity> Hash for Dequeue<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        // elements are hashed one-by-one, since the split between slices is not a part of logical contents
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<'d, T, N: Capacity> IntoIterator for &'d Dequeue<T, N> {
+    type Item = &'d T;
+    type IntoIter = Iter<'d, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'d, T, N: Capacity> IntoIterator for &'d mut Dequeue<T, N> {
+    type Item = &'d mut T;
+    type IntoIter = IterMut<'d, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, N: Capacity> IntoIterator for Dequeue<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+impl<T, N: Capacity> Drop for Dequeue<T, N> {
     #[inline]
     fn drop(&mut self) {
         self.clear();
@@ -0,0 +1,119 @@
+use core::{fmt::Debug, iter::Rev};
+
+use super::{Capacity, Dequeue, Iter};
+
+/// Log of the last `N` recorded elements, evicting the oldest ones once full.
+pub struct RingLog<T, N: Capacity> {
+    /// Elements from the oldest to the latest
+    dequeue: Dequeue<T, N>,
+}
+
+impl<T: Debug, N: Capacity> Debug for RingLog<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, N: Capacity> Clone for RingLog<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            dequeue: self.dequeue.clone(),
+        }
+    }
+}
+
+impl<T, N: Capacity> RingLog<T, N> {
+    /// Creates an empty log.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            dequeue: Dequeue::new(),
+        }
+    }
+
+    /// Records an element, returning the oldest one if it was evicted.
+    #[inline]
+    pub fn push(&mut self, item: T) -> Option<T> {
+        self.dequeue.push_back_evict(item)
+    }
+
+    /// Most recently recorded element.
+    #[inline]
+    pub fn latest(&self) -> Option<&T> {
+        self.dequeue
+            .len()
+            .checked_sub(1)
+            .and_then(|last| self.dequeue.get(last))
+    }
+
+    /// Least recently recorded element, that is still kept.
+    #[inline]
+    pub fn oldest(&self) -> Option<&T> {
+        self.dequeue.get(0)
+    }
+
+    /// Iterates over the elements, from the latest to the oldest.
+    #[inline]
+    pub fn iter(&self) -> Rev<Iter<'_, T>> {
+        self.dequeue.iter().rev()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dequeue.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dequeue.is_empty()
+    }
+
+    /// Maximum number of elements this log keeps.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N::CAPACITY
+    }
+
+    /// Drops all of the recorded elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.dequeue.clear();
+    }
+
+    /// Underlying dequeue, with elements ordered from the oldest to the latest.
+    #[inline]
+    pub fn as_dequeue(&self) -> &Dequeue<T, N> {
+        &self.dequeue
+    }
+
+    /// Unwraps into the underlying dequeue, with elements ordered from the oldest to the latest.
+    #[inline]
+    pub fn into_dequeue(self) -> Dequeue<T, N> {
+        self.dequeue
+    }
+}
+
+impl<T, N: Capacity> Default for RingLog<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'l, T, N: Capacity> IntoIterator for &'l RingLog<T, N> {
+    type Item = &'l T;
+    type IntoIter = Rev<Iter<'l, T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Records all of the elements, keeping the last `N` of them.
+impl<T, N: Capacity> Extend<T> for RingLog<T, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.dequeue.extend_overwrite(iter);
+    }
+}
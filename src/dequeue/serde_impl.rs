@@ -0,0 +1,48 @@
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{Capacity, Dequeue, PushStatus};
+
+/// Serializes elements as a sequence, in logical order.
+impl<T: Serialize, N: Capacity> Serialize for Dequeue<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+/// Deserializes elements from a sequence, failing if it is longer than the capacity.
+impl<'de, T: Deserialize<'de>, N: Capacity> Deserialize<'de> for Dequeue<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(DequeueVisitor(PhantomData))
+    }
+}
+
+struct DequeueVisitor<T, N: Capacity>(PhantomData<fn() -> Dequeue<T, N>>);
+
+impl<'de, T: Deserialize<'de>, N: Capacity> Visitor<'de> for DequeueVisitor<T, N> {
+    type Value = Dequeue<T, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of at most {} elements", N::CAPACITY)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        if let Some(len) = seq.size_hint() {
+            if len > N::CAPACITY {
+                return Err(A::Error::invalid_length(len, &self));
+            }
+        }
+
+        let mut dequeue = Dequeue::new();
+        while let Some(item) = seq.next_element()? {
+            if let PushStatus::Rejected(_) = dequeue.push_back(item) {
+                return Err(A::Error::invalid_length(N::CAPACITY + 1, &self));
+            }
+        }
+        Ok(dequeue)
+    }
+}
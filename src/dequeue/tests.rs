@@ -39,21 +39,16 @@ fn kani_operation<const MAX_IND: usize, T>(
 }
 
 #[cfg(kani)]
-#[cfg_attr(kani, kani::proof)]
-#[cfg_attr(kani, kani::unwind(1000))]
-fn kani_ops_test() {
+fn kani_ops_test<T, N: super::Capacity>(t_generator: impl Fn() -> T) {
     // arrange
 
-    use alloc::boxed::Box;
-    use generic_array::typenum;
-
     use core::hint::black_box;
 
     use super::Dequeue;
-    let mut dequeue = Dequeue::<Box<u8>, typenum::U<10>>::new();
+    let mut dequeue = Dequeue::<T, N>::new();
 
     for _ in 0..10 {
-        let op = kani_operation::<20, _>(|| Box::new(kani::any::<u8>()));
+        let op = kani_operation::<20, _>(&t_generator);
 
         match op {
             DequeueOperation::Create => dequeue = Dequeue::new(),
@@ -86,9 +81,31 @@ fn kani_ops_test() {
             }
             DequeueOperation::Clone => unreachable!(),
         }
+        assert!(dequeue.len() <= N::CAPACITY);
     }
 }
 
+#[cfg(kani)]
+#[cfg_attr(kani, kani::proof)]
+#[cfg_attr(kani, kani::unwind(1000))]
+fn kani_ops_boxed() {
+    kani_ops_test::<Box<u8>, typenum::U<10>>(|| Box::new(kani::any::<u8>()));
+}
+
+#[cfg(kani)]
+#[cfg_attr(kani, kani::proof)]
+#[cfg_attr(kani, kani::unwind(1000))]
+fn kani_ops_zero_capacity() {
+    kani_ops_test::<Box<u8>, typenum::U<0>>(|| Box::new(kani::any::<u8>()));
+}
+
+#[cfg(kani)]
+#[cfg_attr(kani, kani::proof)]
+#[cfg_attr(kani, kani::unwind(1000))]
+fn kani_ops_zero_sized() {
+    kani_ops_test::<(), typenum::U<10>>(|| ());
+}
+
 #[test]
 fn create_drop() {
     let _ = Dequeue::<Box<u8>, typenum::U<10>>::new();
@@ -343,3 +360,745 @@ fn ops_test() {
         }
     }
 }
+
+#[test]
+fn capacity_full() {
+    let mut dequeue = Dequeue::<u8, typenum::U<3>>::new();
+
+    assert_eq!(dequeue.capacity(), 3);
+    assert!(!dequeue.is_full());
+
+    assert!(dequeue.push_back(1).is_success());
+    assert!(dequeue.push_front(2).is_success());
+    assert!(dequeue.push_back(3).is_success());
+    assert!(dequeue.is_full());
+
+    assert_eq!(dequeue.push_back(4), super::PushStatus::Rejected(4));
+    assert_eq!(dequeue.push_front(5).into_result(), Err(5));
+    assert_eq!(dequeue.len(), 3);
+
+    dequeue.clear();
+    assert!(dequeue.is_empty());
+    assert!(!dequeue.is_full());
+}
+
+fn wrapped_dequeue() -> Dequeue<Box<u8>, typenum::U<6>> {
+    let mut dequeue = Dequeue::new();
+    // push to both ends, so that elements wrap around physical end
+    for i in 2..5 {
+        dequeue.push_back(Box::new(i)).assert();
+    }
+    for i in (0..2).rev() {
+        dequeue.push_front(Box::new(i)).assert();
+    }
+    // [0, 1 | 2, 3, 4]
+    dequeue
+}
+
+#[test]
+fn iter() {
+    let mut dequeue = wrapped_dequeue();
+
+    assert!(dequeue.iter().map(|v| **v).eq(0..5));
+    assert!(dequeue.iter().rev().map(|v| **v).eq((0..5).rev()));
+    assert_eq!(dequeue.iter().len(), 5);
+
+    for item in &mut dequeue {
+        **item += 10;
+    }
+    assert!(dequeue.iter_mut().map(|v| **v).eq(10..15));
+
+    let mut into_iter = dequeue.into_iter();
+    assert_eq!(into_iter.len(), 5);
+    assert_eq!(into_iter.next().map(|v| *v), Some(10));
+    assert_eq!(into_iter.next_back().map(|v| *v), Some(14));
+    assert_eq!(into_iter.len(), 3);
+    // rest are dropped with the iterator
+}
+
+#[test]
+fn drain() {
+    for from in 0..=5 {
+        for to in from..=5 {
+            let mut dequeue = wrapped_dequeue();
+
+            assert!(dequeue.drain(from..to).map(|v| *v).eq(from as u8..to as u8));
+            assert_eq!(dequeue.len(), 5 - (to - from));
+            assert!(dequeue
+                .iter()
+                .map(|v| **v)
+                .eq((0..from as u8).chain(to as u8..5)));
+
+            // partially consumed drain
+            let mut dequeue = wrapped_dequeue();
+            let mut drain = dequeue.drain(from..to);
+            let _ = drain.next_back();
+            drop(drain);
+            assert!(dequeue
+                .iter()
+                .map(|v| **v)
+                .eq((0..from as u8).chain(to as u8..5)));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn drain_out_of_bounds() {
+    let mut dequeue = wrapped_dequeue();
+    dequeue.drain(2..6);
+}
+
+#[test]
+fn slices() {
+    let mut dequeue = wrapped_dequeue();
+
+    let (first, second) = dequeue.as_slices();
+    assert!(first.iter().map(|v| **v).eq(0..2));
+    assert!(second.iter().map(|v| **v).eq(2..5));
+
+    let (first, second) = dequeue.as_mut_slices();
+    *first[0] = 42;
+    *second[0] += 10;
+
+    let contiguous = dequeue.make_contiguous();
+    assert!(contiguous.iter().map(|v| **v).eq([42, 1, 12, 3, 4]));
+    assert_eq!(*dequeue.start, 0);
+
+    let (first, second) = dequeue.as_slices();
+    assert!(first.iter().map(|v| **v).eq([42, 1, 12, 3, 4]));
+    assert!(second.is_empty());
+}
+
+#[test]
+fn random_access_matches_vec_dequeue() {
+    use alloc::collections::VecDeque;
+
+    let mut rand = thread_rng();
+    let mut dequeue = Dequeue::<Box<u8>, typenum::U<7>>::new();
+    let mut reference = VecDeque::<Box<u8>>::new();
+
+    for _ in 0..2000 {
+        let len = reference.len();
+        match rand.gen_range(0..8) {
+            0 => {
+                let item: u8 = rand.gen();
+                let _ = dequeue.push_front(Box::new(item));
+                if len < 7 {
+                    reference.push_front(Box::new(item));
+                }
+            }
+            1 => {
+                let index = rand.gen_range(0..=len);
+                let item: u8 = rand.gen();
+                let status = dequeue.insert(index, Box::new(item));
+                if len < 7 {
+                    assert!(status.is_success());
+                    reference.insert(index, Box::new(item));
+                } else {
+                    assert!(status.is_rejected());
+                }
+            }
+            2 => {
+                let index = rand.gen_range(0..=len);
+                assert_eq!(dequeue.remove(index), reference.remove(index));
+            }
+            3 if len > 0 => {
+                let (i, j) = (rand.gen_range(0..len), rand.gen_range(0..len));
+                dequeue.swap(i, j);
+                reference.swap(i, j);
+            }
+            4 => {
+                let index = rand.gen_range(0..=len);
+                assert_eq!(
+                    dequeue.swap_remove_front(index),
+                    reference.swap_remove_front(index)
+                );
+            }
+            5 => {
+                let index = rand.gen_range(0..=len);
+                assert_eq!(
+                    dequeue.swap_remove_back(index),
+                    reference.swap_remove_back(index)
+                );
+            }
+            6 => {
+                let n = rand.gen_range(0..=len);
+                dequeue.rotate_left(n);
+                reference.rotate_left(n);
+            }
+            7 => {
+                let n = rand.gen_range(0..=len);
+                dequeue.rotate_right(n);
+                reference.rotate_right(n);
+            }
+            _ => {
+                let item: u8 = rand.gen();
+                let _ = dequeue.push_back(Box::new(item));
+                if len < 7 {
+                    reference.push_back(Box::new(item));
+                }
+            }
+        }
+
+        assert!(dequeue.iter().eq(reference.iter()));
+    }
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut dequeue = wrapped_dequeue();
+    let _ = dequeue.insert(6, Box::new(0));
+}
+
+#[test]
+fn try_extend() {
+    let mut dequeue = Dequeue::<u8, typenum::U<4>>::new();
+
+    dequeue.try_extend([1, 2]).unwrap();
+    assert!(dequeue.iter().eq(&[1, 2]));
+
+    let leftovers = dequeue.try_extend(3..10).unwrap_err();
+    assert_eq!(leftovers.rejected, 5);
+    assert!(leftovers.rest.clone().eq(6..10));
+    assert!(leftovers.into_iter().eq(5..10));
+    assert!(dequeue.iter().eq(&[1, 2, 3, 4]));
+
+    // exactly fitting iterator is fine
+    dequeue.clear();
+    dequeue.try_extend(0..4).unwrap();
+    assert!(dequeue.is_full());
+}
+
+#[test]
+fn extend_overwrite() {
+    let mut dequeue = Dequeue::<Box<u8>, typenum::U<4>>::new();
+
+    dequeue.extend_overwrite((0..10).map(Box::new));
+    assert!(dequeue.iter().map(|v| **v).eq(6..10));
+}
+
+#[test]
+fn extend_collect() {
+    let mut dequeue: Dequeue<u8, typenum::U<4>> = (0..2).collect();
+    dequeue.extend(&[2, 3]);
+    assert!(dequeue.iter().copied().eq(0..4));
+}
+
+#[test]
+#[should_panic]
+fn extend_overflow() {
+    let _: Dequeue<u8, typenum::U<4>> = (0..5).collect();
+}
+
+#[test]
+fn retain() {
+    let mut dequeue = wrapped_dequeue();
+    dequeue.retain(|v| **v % 2 == 0);
+    assert!(dequeue.iter().map(|v| **v).eq([0, 2, 4]));
+
+    let mut dequeue = wrapped_dequeue();
+    dequeue.retain_mut(|v| {
+        **v *= 10;
+        **v != 30
+    });
+    assert!(dequeue.iter().map(|v| **v).eq([0, 10, 20, 40]));
+
+    let mut dequeue = wrapped_dequeue();
+    dequeue.retain(|_| false);
+    assert!(dequeue.is_empty());
+}
+
+#[test]
+fn retain_panic() {
+    extern crate std;
+
+    let mut dequeue = wrapped_dequeue();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        dequeue.retain(|v| {
+            assert_ne!(**v, 3, "Predicate panic");
+            **v != 1
+        })
+    }));
+    assert!(result.is_err());
+
+    // processed elements are filtered, the rest is preserved
+    assert!(dequeue.iter().map(|v| **v).eq([0, 2, 3, 4]));
+}
+
+#[test]
+fn dedup() {
+    let mut dequeue: Dequeue<Box<u8>, typenum::U<10>> = [1, 1, 2, 3, 3, 3, 1, 4, 4]
+        .into_iter()
+        .map(Box::new)
+        .collect();
+    dequeue.rotate_left(4);
+    // [3, 3, 1, 4, 4, 1, 1, 2, 3]
+    dequeue.dedup();
+    assert!(dequeue.iter().map(|v| **v).eq([3, 1, 4, 1, 2, 3]));
+
+    dequeue.dedup_by_key(|v| **v % 2);
+    assert!(dequeue.iter().map(|v| **v).eq([3, 4, 1, 2, 3]));
+
+    dequeue.dedup_by(|a, b| **a > **b);
+    assert!(dequeue.iter().map(|v| **v).eq([3, 1]));
+}
+
+#[test]
+fn truncate() {
+    let mut dequeue = wrapped_dequeue();
+    dequeue.truncate_back(10);
+    assert_eq!(dequeue.len(), 5);
+    dequeue.truncate_back(4);
+    assert!(dequeue.iter().map(|v| **v).eq(0..4));
+    dequeue.truncate_front(2);
+    assert!(dequeue.iter().map(|v| **v).eq(2..4));
+    dequeue.truncate_front(0);
+    assert!(dequeue.is_empty());
+}
+
+#[test]
+fn split_off_append() {
+    let mut dequeue = wrapped_dequeue();
+
+    let mut tail = dequeue.split_off::<typenum::U<3>>(2);
+    assert!(dequeue.iter().map(|v| **v).eq(0..2));
+    assert!(tail.iter().map(|v| **v).eq(2..5));
+    assert!(tail.is_full());
+
+    let mut other = Dequeue::<Box<u8>, typenum::U<2>>::new();
+    other.push_back(Box::new(5)).assert();
+    assert_eq!(
+        tail.append(&mut other),
+        Err(super::CapacityError {
+            required: 1,
+            available: 0
+        })
+    );
+    assert_eq!(other.len(), 1, "Failed append should not modify the source");
+
+    dequeue.append(&mut tail).unwrap();
+    dequeue.append(&mut other).unwrap();
+    assert!(dequeue.iter().map(|v| **v).eq(0..6));
+    assert!(tail.is_empty());
+    assert!(other.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn split_off_overflow() {
+    let mut dequeue = wrapped_dequeue();
+    let _ = dequeue.split_off::<typenum::U<2>>(2);
+}
+
+#[test]
+fn compare_hash() {
+    use core::hash::{Hash, Hasher};
+    use hashers::pigeon::Bricolage;
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = Bricolage::default();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let wrapped = wrapped_dequeue();
+    let mut contiguous = Dequeue::<Box<u8>, typenum::U<5>>::new();
+    contiguous.extend((0..5).map(Box::new));
+    assert_ne!(*wrapped.start, *contiguous.start);
+
+    assert_eq!(wrapped, contiguous);
+    assert_eq!(wrapped, [0, 1, 2, 3, 4].map(Box::new));
+    assert_eq!(
+        wrapped,
+        &(0..5).map(Box::new).collect::<alloc::vec::Vec<_>>()[..]
+    );
+    assert_ne!(wrapped, [0, 1, 2, 3].map(Box::new));
+    assert_eq!(
+        wrapped.partial_cmp(&contiguous),
+        Some(core::cmp::Ordering::Equal)
+    );
+
+    let mut same_capacity = Dequeue::<Box<u8>, typenum::U<6>>::new();
+    same_capacity.extend((0..5).map(Box::new));
+    assert_eq!(wrapped.cmp(&same_capacity), core::cmp::Ordering::Equal);
+    assert_eq!(hash(&wrapped), hash(&same_capacity));
+
+    same_capacity.pop_back();
+    assert!(wrapped > same_capacity);
+    assert_ne!(hash(&wrapped), hash(&same_capacity));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    assert_tokens(
+        &wrapped_dequeue(),
+        &[
+            Token::Seq { len: Some(5) },
+            Token::U8(0),
+            Token::U8(1),
+            Token::U8(2),
+            Token::U8(3),
+            Token::U8(4),
+            Token::SeqEnd,
+        ],
+    );
+
+    assert_de_tokens_error::<Dequeue<u8, typenum::U<2>>>(
+        // length is checked upfront, if available
+        &[Token::Seq { len: Some(3) }],
+        "invalid length 3, expected a sequence of at most 2 elements",
+    );
+    assert_de_tokens_error::<Dequeue<u8, typenum::U<2>>>(
+        &[
+            Token::Seq { len: None },
+            Token::U8(0),
+            Token::U8(1),
+            Token::U8(2),
+        ],
+        "invalid length 3, expected a sequence of at most 2 elements",
+    );
+}
+
+#[test]
+fn slice_copies() {
+    let mut dequeue = Dequeue::<u8, typenum::U<6>>::new();
+    dequeue.push_back(0).assert();
+    dequeue.push_front(0).assert();
+    let _ = dequeue.pop_front();
+    let _ = dequeue.pop_front();
+    // empty, with start at the physical end
+
+    assert_eq!(dequeue.push_back_from_slice(&[1, 2, 3, 4]), 4);
+    assert_eq!(dequeue.push_back_from_slice(&[5, 6, 7]), 2);
+    assert_eq!(dequeue.push_back_from_slice(&[8]), 0);
+    assert_eq!(dequeue, [1, 2, 3, 4, 5, 6]);
+
+    let mut out = [0; 4];
+    assert_eq!(dequeue.pop_front_into_slice(&mut out), 4);
+    assert_eq!(out, [1, 2, 3, 4]);
+    assert_eq!(dequeue.push_back_from_slice(&[7, 8, 9, 10, 11]), 4);
+    assert_eq!(dequeue.pop_front_into_slice(&mut out), 4);
+    assert_eq!(out, [5, 6, 7, 8]);
+    assert_eq!(dequeue.pop_front_into_slice(&mut out), 2);
+    assert_eq!(out[..2], [9, 10]);
+    assert!(dequeue.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn io() {
+    extern crate std;
+    use std::io::{BufRead, Read, Write};
+
+    let mut dequeue = Dequeue::<u8, typenum::U<8>>::new();
+
+    assert_eq!(dequeue.write(b"hello, world").unwrap(), 8);
+    assert_eq!(dequeue.write(b"!").unwrap(), 0);
+    assert!(dequeue.write_all(b"!").is_err());
+
+    let mut hello = [0; 5];
+    dequeue.read_exact(&mut hello).unwrap();
+    assert_eq!(&hello, b"hello");
+
+    dequeue.write_all(b"wor").unwrap();
+    // contents wrap around now
+    assert_eq!(dequeue.fill_buf().unwrap(), b", w");
+    dequeue.consume(2);
+
+    let mut rest = alloc::vec::Vec::new();
+    dequeue.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b"wwor");
+}
+
+#[test]
+fn push_evict() {
+    let mut dequeue = Dequeue::<Box<u8>, typenum::U<3>>::new();
+
+    assert_eq!(dequeue.push_back_evict(Box::new(1)), None);
+    assert_eq!(dequeue.push_back_evict(Box::new(2)), None);
+    assert_eq!(dequeue.push_front_evict(Box::new(0)), None);
+    // [0, 1, 2]
+    assert_eq!(dequeue.push_back_evict(Box::new(3)), Some(Box::new(0)));
+    // [1, 2, 3]
+    assert_eq!(dequeue.push_front_evict(Box::new(4)), Some(Box::new(3)));
+    // [4, 1, 2]
+    assert_eq!(dequeue, [4, 1, 2].map(Box::new));
+}
+
+#[test]
+fn ring_log() {
+    let mut log = super::RingLog::<Box<u8>, typenum::U<3>>::new();
+    assert_eq!(log.latest(), None);
+    assert_eq!(log.oldest(), None);
+
+    assert_eq!(log.push(Box::new(1)), None);
+    assert_eq!(log.latest().map(|v| **v), Some(1));
+    assert_eq!(log.oldest().map(|v| **v), Some(1));
+
+    log.extend([2, 3].map(Box::new));
+    assert_eq!(log.push(Box::new(4)), Some(Box::new(1)));
+    assert_eq!(log.latest().map(|v| **v), Some(4));
+    assert_eq!(log.oldest().map(|v| **v), Some(2));
+    assert!(log.iter().map(|v| **v).eq([4, 3, 2]));
+    assert_eq!(log.into_dequeue(), [2, 3, 4].map(Box::new));
+}
+
+#[test]
+fn zero_capacity() {
+    let mut dequeue = Dequeue::<Box<u8>, typenum::U<0>>::new();
+    assert!(dequeue.is_empty());
+    assert!(dequeue.is_full());
+    assert_eq!(dequeue.capacity(), 0);
+
+    assert_eq!(
+        dequeue.push_back(Box::new(1)),
+        super::PushStatus::Rejected(Box::new(1))
+    );
+    assert_eq!(
+        dequeue.push_front(Box::new(2)),
+        super::PushStatus::Rejected(Box::new(2))
+    );
+    assert_eq!(
+        dequeue.insert(0, Box::new(3)),
+        super::PushStatus::Rejected(Box::new(3))
+    );
+    assert_eq!(dequeue.push_back_evict(Box::new(4)), Some(Box::new(4)));
+    assert_eq!(dequeue.push_front_evict(Box::new(5)), Some(Box::new(5)));
+    dequeue.push_back_overwrite(Box::new(6));
+    dequeue.push_front_overwrite(Box::new(7));
+    dequeue.extend_overwrite([8, 9].map(Box::new));
+    let leftovers = dequeue.try_extend([10, 11].map(Box::new)).unwrap_err();
+    assert!(leftovers.into_iter().map(|v| *v).eq([10, 11]));
+
+    assert_eq!(dequeue.pop_back(), None);
+    assert_eq!(dequeue.pop_front(), None);
+    assert_eq!(dequeue.get(0), None);
+    assert_eq!(dequeue.get_mut(0), None);
+    assert_eq!(dequeue.remove(0), None);
+    assert_eq!(dequeue.swap_remove_front(0), None);
+    assert_eq!(dequeue.swap_remove_back(0), None);
+    dequeue.rotate_left(0);
+    dequeue.rotate_right(0);
+    dequeue.retain(|_| true);
+    dequeue.dedup();
+    dequeue.truncate_back(0);
+    dequeue.truncate_front(0);
+    assert_eq!(dequeue.drain(..).count(), 0);
+    assert!(dequeue.make_contiguous().is_empty());
+    assert_eq!(dequeue.as_slices(), (&[][..], &[][..]));
+    assert_eq!(dequeue.iter().count(), 0);
+    assert_eq!(dequeue.iter_mut().count(), 0);
+
+    let mut other = dequeue.split_off::<typenum::U<0>>(0);
+    assert_eq!(dequeue.append(&mut other), Ok(()));
+    let mut one = Dequeue::<Box<u8>, typenum::U<1>>::new();
+    one.push_back(Box::new(12)).assert();
+    assert_eq!(
+        dequeue.append(&mut one),
+        Err(super::CapacityError {
+            required: 1,
+            available: 0,
+        })
+    );
+
+    assert_eq!(dequeue.clone(), dequeue);
+    dequeue.clear();
+    assert_eq!(dequeue.into_iter().count(), 0);
+
+    let mut bytes = Dequeue::<u8, super::ConstCapacity<0>>::new();
+    assert_eq!(bytes.push_back_from_slice(&[1, 2, 3]), 0);
+    assert_eq!(bytes.pop_front_into_slice(&mut [0; 3]), 0);
+}
+
+#[test]
+fn zero_sized() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Zst;
+
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            DROPPED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut dequeue = Dequeue::<Zst, typenum::U<4>>::new();
+    dequeue.push_back(Zst).assert();
+    dequeue.push_back(Zst).assert();
+    assert_eq!(dequeue.pop_front(), Some(Zst));
+    dequeue.push_back(Zst).assert();
+    dequeue.push_back(Zst).assert();
+    dequeue.push_front(Zst).assert();
+    // wrapped around the physical end
+    assert_eq!(dequeue.len(), 4);
+    assert_eq!(dequeue.push_back_evict(Zst), Some(Zst));
+    assert_eq!(dequeue.as_slices().0.len() + dequeue.as_slices().1.len(), 4);
+    dequeue.rotate_left(1);
+    dequeue.retain(|_| true);
+    dequeue.dedup();
+    assert_eq!(dequeue.len(), 1);
+    dequeue.extend_overwrite([Zst, Zst, Zst]);
+    assert_eq!(dequeue.make_contiguous().len(), 4);
+    assert_eq!(dequeue.drain(1..3).count(), 2);
+    assert_eq!(dequeue.clone().into_iter().count(), 2);
+
+    // every created element is dropped exactly once
+    drop(dequeue);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 13);
+}
+
+#[test]
+fn array_conversions() {
+    use generic_array::GenericArray;
+
+    let dequeue = Dequeue::<Box<u8>, typenum::U<4>>::from_array([1, 2].map(Box::new));
+    assert_eq!(dequeue, [1, 2].map(Box::new));
+    // not full - handed back
+    let dequeue = <[Box<u8>; 4]>::try_from(dequeue).unwrap_err();
+    assert_eq!(dequeue.len(), 2);
+
+    let array = GenericArray::from_array([0, 1, 2, 3].map(Box::new));
+    let mut dequeue = Dequeue::<Box<u8>, typenum::U<4>>::from(array);
+    assert!(dequeue.is_full());
+    // wrap around the end
+    dequeue.rotate_left(3);
+    let array: [Box<u8>; 4] = dequeue.try_into().unwrap();
+    assert_eq!(array, [3, 0, 1, 2].map(Box::new));
+
+    let dequeue = Dequeue::<Box<u8>, typenum::U<4>>::from_array([4, 5, 6, 7].map(Box::new));
+    let array = GenericArray::try_from(dequeue).unwrap();
+    assert_eq!(array.as_slice(), [4, 5, 6, 7].map(Box::new));
+
+    let dequeue = Dequeue::<Box<u8>, super::ConstCapacity<3>>::from([8, 9, 10].map(Box::new));
+    let array: [Box<u8>; 3] = dequeue.try_into().unwrap();
+    assert_eq!(array, [8, 9, 10].map(Box::new));
+
+    let (data, len) = wrapped_dequeue().into_array_partial();
+    assert_eq!(len, 5);
+    for (i, item) in data[..len].iter().enumerate() {
+        // SAFETY: first `len` positions are initialized
+        let item = unsafe { item.assume_init_read() };
+        assert_eq!(*item, i as u8);
+    }
+}
+
+#[test]
+fn try_apis() {
+    use crate::Error;
+
+    let mut dequeue = wrapped_dequeue();
+    assert_eq!(
+        dequeue.try_get(5),
+        Err(Error::IndexOutOfRange { index: 5, len: 5 })
+    );
+    assert_eq!(dequeue.try_get_mut(4).map(|v| **v), Ok(4));
+    assert_eq!(
+        dequeue.try_swap(0, 7),
+        Err(Error::IndexOutOfRange { index: 7, len: 5 })
+    );
+    assert_eq!(
+        dequeue.try_rotate_left(6),
+        Err(Error::IndexOutOfRange { index: 6, len: 5 })
+    );
+    assert_eq!(
+        dequeue.try_rotate_right(6),
+        Err(Error::IndexOutOfRange { index: 6, len: 5 })
+    );
+    assert!(matches!(
+        dequeue.try_drain(3..6),
+        Err(Error::IndexOutOfRange { index: 6, len: 5 })
+    ));
+    #[allow(clippy::reversed_empty_ranges)]
+    {
+        assert!(matches!(
+            dequeue.try_drain(3..2),
+            Err(Error::IndexOutOfRange { index: 3, len: 2 })
+        ));
+    }
+    assert_eq!(
+        dequeue.try_split_off::<typenum::U<1>>(3).unwrap_err(),
+        Error::Capacity(super::CapacityError {
+            required: 2,
+            available: 1,
+        })
+    );
+    // nothing was modified so far
+    assert_eq!(dequeue, [0, 1, 2, 3, 4].map(Box::new));
+
+    assert_eq!(
+        dequeue.try_insert(6, Box::new(5)),
+        Err((Error::IndexOutOfRange { index: 6, len: 5 }, Box::new(5)))
+    );
+    dequeue.try_insert(5, Box::new(5)).unwrap();
+    assert!(matches!(
+        dequeue.try_insert(0, Box::new(6)),
+        Err((Error::Capacity(_), _))
+    ));
+
+    dequeue.try_swap(0, 5).unwrap();
+    dequeue.try_rotate_right(1).unwrap();
+    assert_eq!(dequeue, [0, 5, 1, 2, 3, 4].map(Box::new));
+    assert!(dequeue.try_drain(1..=1).unwrap().eq([Box::new(5)]));
+    assert_eq!(
+        dequeue.try_split_off::<typenum::U<2>>(3).unwrap(),
+        [3, 4].map(Box::new)
+    );
+
+    assert_eq!(
+        Dequeue::<u8, typenum::U<2>>::try_from_iter(0..2).unwrap(),
+        [0, 1]
+    );
+    assert_eq!(
+        Dequeue::<u8, typenum::U<2>>::try_from_iter(0..3),
+        Err(Error::Capacity(super::CapacityError {
+            required: 3,
+            available: 2,
+        }))
+    );
+}
+
+#[test]
+fn error_display() {
+    extern crate std;
+    use std::string::ToString;
+
+    use core::error::Error as _;
+
+    let error = crate::Error::IndexOutOfRange { index: 3, len: 2 };
+    assert_eq!(
+        error.to_string(),
+        "Index out of bounds: index 3, but length 2"
+    );
+    assert!(error.source().is_none());
+
+    let error = crate::Error::from(super::CapacityError {
+        required: 3,
+        available: 1,
+    });
+    assert_eq!(
+        error.to_string(),
+        "Dequeue out of capacity: 3 elements required, but only 1 available"
+    );
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn get_disjoint_mut() {
+    let mut dequeue = wrapped_dequeue();
+    let [a, b, c] = dequeue.get_disjoint_mut([4, 0, 2]).unwrap();
+    core::mem::swap(a, b);
+    **c = 10;
+    assert_eq!(dequeue, [4, 1, 10, 3, 0].map(Box::new));
+
+    assert!(dequeue.get_disjoint_mut([1, 1]).is_none());
+    assert!(dequeue.get_disjoint_mut([0, 5]).is_none());
+    assert!(dequeue.get_disjoint_mut([]).is_some());
+}
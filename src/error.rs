@@ -0,0 +1,74 @@
+use crate::dequeue::CapacityError;
+
+/// Reason a fallible operation of this crate failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Operation requires more free space, than there is.
+    Capacity(CapacityError),
+    /// Index (or range bound) lies beyond the length it is checked against.
+    IndexOutOfRange {
+        /// Offending index.
+        index: usize,
+        /// Length the index was checked against.
+        len: usize,
+    },
+    /// Source iterator ran out of elements, after yielding `available` of them.
+    Exhausted {
+        /// Number of elements, that could be obtained.
+        available: usize,
+    },
+}
+
+impl From<CapacityError> for Error {
+    #[inline]
+    fn from(error: CapacityError) -> Self {
+        Self::Capacity(error)
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Capacity(error) => error.fmt(f),
+            Error::IndexOutOfRange { index, len } => {
+                write!(f, "Index out of bounds: index {index}, but length {len}")
+            }
+            Error::Exhausted { available } => {
+                write!(
+                    f,
+                    "Iterator exhausted, only {available} elements are available"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Capacity(error) => Some(error),
+            Error::IndexOutOfRange { .. } | Error::Exhausted { .. } => None,
+        }
+    }
+}
+
+/// Unwraps an option, that is guaranteed to be `Some` by the crate invariants.
+///
+/// Panics otherwise, unless the `no-panic` feature is enabled.
+/// With it, the check is skipped even in debug builds, trading the panic on a broken invariant for undefined behavior.
+///
+/// # Safety
+/// Option must be `Some`. With the `no-panic` feature, `None` is undefined behavior.
+#[inline]
+#[track_caller]
+pub(crate) unsafe fn unwrap_invariant<T>(option: Option<T>, reason: &str) -> T {
+    #[cfg(not(feature = "no-panic"))]
+    {
+        option.expect(reason)
+    }
+    #[cfg(feature = "no-panic")]
+    {
+        let _ = reason;
+        option.unwrap_unchecked()
+    }
+}
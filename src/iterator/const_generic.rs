@@ -0,0 +1,189 @@
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{dequeue::ConstCapacity, error::unwrap_invariant, Error};
+
+use super::BPeekN;
+
+/// [`BPeekN`] with capacity specified as a const generic, backed by a plain array.
+///
+/// Capacity checks on peeking are performed at compile time.
+pub type BPeek<I, const N: usize> = BPeekN<I, ConstCapacity<N>>;
+
+impl<I: Iterator, const N: usize> BPeekN<I, ConstCapacity<N>> {
+    #[inline]
+    pub fn bpeek<const OFF: usize>(&mut self) -> Option<ConstPeekCursor<'_, I, N, OFF>> {
+        self.try_bpeek().ok()
+    }
+
+    /// Same as [`BPeek::bpeek`], but reports how many elements are available, if there's not enough of them.
+    #[inline]
+    pub fn try_bpeek<const OFF: usize>(&mut self) -> Result<ConstPeekCursor<'_, I, N, OFF>, Error> {
+        let () = ConstPeekCursor::<I, N, OFF>::VALID;
+        self.fill_to(OFF)?;
+        Ok(ConstPeekCursor { iter: self })
+    }
+
+    #[inline]
+    pub fn bpeek1(&mut self) -> Option<ConstPeekCursor<'_, I, N, 1>> {
+        self.bpeek()
+    }
+
+    #[inline]
+    pub fn bpeek2(&mut self) -> Option<ConstPeekCursor<'_, I, N, 2>> {
+        self.bpeek()
+    }
+
+    #[inline]
+    pub fn bpeek3(&mut self) -> Option<ConstPeekCursor<'_, I, N, 3>> {
+        self.bpeek()
+    }
+
+    /// Consumes and returns the next `K` elements, if all of them together satisfy the predicate.
+    ///
+    /// If there are less than `K` elements, or predicate fails, nothing is consumed.
+    pub fn next_if_seq<const K: usize>(
+        &mut self,
+        pred: impl FnOnce(&[&I::Item; K]) -> bool,
+    ) -> Option<[I::Item; K]> {
+        let cursor = self.bpeek::<K>()?;
+        if !pred(&cursor.peek_all()) {
+            return None;
+        }
+        Some(cursor.take_all())
+    }
+
+    /// Consumes and returns the next `K` elements, if they are equal to `expected`.
+    ///
+    /// If there are less than `K` elements, or they are not equal, nothing is consumed.
+    #[inline]
+    pub fn next_if_eq_seq<T, const K: usize>(&mut self, expected: &[T; K]) -> Option<[I::Item; K]>
+    where
+        I::Item: PartialEq<T>,
+    {
+        self.next_if_seq(|peeked| {
+            peeked
+                .iter()
+                .zip(expected)
+                .all(|(item, expected)| *item == expected)
+        })
+    }
+}
+
+/// Same as [`PeekCursor`](super::PeekCursor), but for [`BPeek`].
+pub struct ConstPeekCursor<'iter, I: Iterator, const N: usize, const IND: usize> {
+    iter: &'iter mut BPeek<I, N>,
+}
+
+impl<I: Iterator, const N: usize, const IND: usize> Debug for ConstPeekCursor<'_, I, N, IND>
+where
+    I: Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConstPeekCursor")
+            .field("iter", &*self.iter)
+            .finish()
+    }
+}
+
+impl<I: Iterator, const N: usize, const IND: usize> Deref for ConstPeekCursor<'_, I, N, IND> {
+    type Target = I::Item;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.get(IND - 1),
+                "Should be present, since number of buffered elements is ensured on construction",
+            )
+        }
+    }
+}
+
+impl<I: Iterator, const N: usize, const IND: usize> DerefMut for ConstPeekCursor<'_, I, N, IND> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.get_mut(IND - 1),
+                "Should be present, since number of buffered elements is ensured on construction",
+            )
+        }
+    }
+}
+
+impl<'iter, I: Iterator, const N: usize, const IND: usize> ConstPeekCursor<'iter, I, N, IND> {
+    /// Evaluates to a compile error, if the cursor is out of the buffer bounds.
+    const VALID: () = {
+        assert!(IND >= 1, "There's no such thing as peeking 0th element");
+        assert!(IND <= N, "Not enough space in the buffer to peek this far");
+    };
+
+    /// Consumes all of the peeked elements, returning them in order.
+    pub fn take_all(self) -> [I::Item; IND] {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.pop_front_array(),
+                "Must be present, number of available elements is ensured on construction",
+            )
+        }
+    }
+
+    /// Returns references to all of the peeked elements, in order.
+    pub fn peek_all(&self) -> [&I::Item; IND] {
+        core::array::from_fn(|i| {
+            // SAFETY: number of buffered elements is ensured on construction
+            unsafe {
+                unwrap_invariant(
+                    self.iter.queue.get(i),
+                    "Must be present, number of available elements is ensured on construction",
+                )
+            }
+        })
+    }
+
+    /// Returns mutable references to all of the peeked elements, in order.
+    pub fn peek_all_mut(&mut self) -> [&mut I::Item; IND] {
+        // SAFETY: number of buffered elements is ensured on construction, and indices are distinct
+        unsafe {
+            unwrap_invariant(
+                self.iter
+                    .queue
+                    .get_disjoint_mut(core::array::from_fn(|i| i)),
+                "Must be present, number of available elements is ensured on construction",
+            )
+        }
+    }
+
+    pub fn peek_prev<const PREV: usize>(self) -> ConstPeekCursor<'iter, I, N, PREV> {
+        const {
+            assert!(
+                PREV + 1 == IND,
+                "Previous cursor must point one element back"
+            );
+        }
+        let () = ConstPeekCursor::<I, N, PREV>::VALID;
+        // no checks necessary, all previous elements are available
+        ConstPeekCursor { iter: self.iter }
+    }
+
+    pub fn peek_forward<const NEXT: usize>(
+        self,
+    ) -> Result<ConstPeekCursor<'iter, I, N, NEXT>, Self> {
+        const {
+            assert!(
+                NEXT == IND + 1,
+                "Next cursor must point one element forward"
+            );
+        }
+        let () = ConstPeekCursor::<I, N, NEXT>::VALID;
+        if self.iter.fill_to(NEXT).is_err() {
+            return Err(self);
+        }
+        Ok(ConstPeekCursor { iter: self.iter })
+    }
+}
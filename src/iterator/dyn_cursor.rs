@@ -0,0 +1,166 @@
+use core::{
+    fmt::Debug,
+    iter::Take,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    dequeue::{Capacity, Drain, Iter, IterMut},
+    error::unwrap_invariant,
+};
+
+use super::BPeekN;
+
+impl<I: Iterator, N: Capacity> BPeekN<I, N> {
+    /// Returns a reference to the `n`th upcoming element, counting from zero.
+    ///
+    /// Returns `None`, if there are not enough elements, or it lies beyond the buffer capacity.
+    #[inline]
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        if n >= N::CAPACITY {
+            return None;
+        }
+        self.fill_to(n + 1).ok()?;
+        self.queue.get(n)
+    }
+
+    /// Returns a mutable reference to the `n`th upcoming element, counting from zero.
+    ///
+    /// Returns `None`, if there are not enough elements, or it lies beyond the buffer capacity.
+    #[inline]
+    pub fn peek_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
+        if n >= N::CAPACITY {
+            return None;
+        }
+        self.fill_to(n + 1).ok()?;
+        self.queue.get_mut(n)
+    }
+
+    /// Returns a cursor over `count` upcoming elements, with `count` known only at runtime.
+    ///
+    /// Returns `None` if there are not enough elements, `count` exceeds the buffer capacity, or `count` is zero, since there's no such thing as peeking 0th element.
+    #[inline]
+    pub fn bpeek_dyn(&mut self, count: usize) -> Option<DynPeekCursor<'_, I, N>> {
+        if count == 0 || count > N::CAPACITY {
+            return None;
+        }
+        self.fill_to(count).ok()?;
+        Some(DynPeekCursor {
+            iter: self,
+            ind: count,
+        })
+    }
+}
+
+/// Same as [`PeekCursor`](super::PeekCursor), but with position known only at runtime, see [`BPeekN::bpeek_dyn`].
+pub struct DynPeekCursor<'iter, I: Iterator, N: Capacity> {
+    iter: &'iter mut BPeekN<I, N>,
+    /// Number of peeked elements, always non-zero
+    ind: usize,
+}
+
+impl<I: Iterator, N: Capacity> Debug for DynPeekCursor<'_, I, N>
+where
+    I: Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynPeekCursor")
+            .field("iter", &*self.iter)
+            .field("ind", &self.ind)
+            .finish()
+    }
+}
+
+impl<I: Iterator, N: Capacity> Deref for DynPeekCursor<'_, I, N> {
+    type Target = I::Item;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.get(self.ind - 1),
+                "Should be present, since number of buffered elements is ensured on construction",
+            )
+        }
+    }
+}
+
+impl<I: Iterator, N: Capacity> DerefMut for DynPeekCursor<'_, I, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.get_mut(self.ind - 1),
+                "Should be present, since number of buffered elements is ensured on construction",
+            )
+        }
+    }
+}
+
+impl<'iter, I: Iterator, N: Capacity> DynPeekCursor<'iter, I, N> {
+    /// Number of peeked elements.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.ind
+    }
+
+    /// Consumes all of the peeked elements, returning them in order.
+    pub fn take_all(self) -> Drain<'iter, I::Item, N> {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.try_drain(..self.ind).ok(),
+                "Must be present, number of available elements is ensured on construction",
+            )
+        }
+    }
+
+    /// Returns references to all of the peeked elements, in order.
+    pub fn peek_all(&self) -> Take<Iter<'_, I::Item>> {
+        self.iter.queue.iter().take(self.ind)
+    }
+
+    /// Returns mutable references to all of the peeked elements, in order.
+    pub fn peek_all_mut(&mut self) -> Take<IterMut<'_, I::Item>> {
+        self.iter.queue.iter_mut().take(self.ind)
+    }
+
+    /// Moves the cursor one element back.
+    ///
+    /// Fails, if cursor already points at the first element.
+    pub fn back(self) -> Result<Self, Self> {
+        if self.ind == 1 {
+            return Err(self);
+        }
+        Ok(Self {
+            iter: self.iter,
+            ind: self.ind - 1,
+        })
+    }
+
+    /// Moves the cursor one element forward.
+    ///
+    /// Fails, if there are no more elements, or the next one lies beyond the buffer capacity.
+    pub fn forward(self) -> Result<Self, Self> {
+        if self.ind == N::CAPACITY || self.iter.fill_to(self.ind + 1).is_err() {
+            return Err(self);
+        }
+        Ok(Self {
+            iter: self.iter,
+            ind: self.ind + 1,
+        })
+    }
+
+    /// Same as [`DynPeekCursor::back`], named after [`PeekCursor::peek_prev`](super::PeekCursor::peek_prev).
+    #[inline]
+    pub fn peek_prev(self) -> Result<Self, Self> {
+        self.back()
+    }
+
+    /// Same as [`DynPeekCursor::forward`], named after [`PeekCursor::peek_forward`](super::PeekCursor::peek_forward).
+    #[inline]
+    pub fn peek_forward(self) -> Result<Self, Self> {
+        self.forward()
+    }
+}
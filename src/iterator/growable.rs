@@ -0,0 +1,299 @@
+use alloc::collections::{vec_deque, VecDeque};
+use core::{
+    fmt::Debug,
+    iter::FusedIterator,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{dequeue::CapacityError, error::unwrap_invariant};
+
+/// Same as [`BPeekN`](super::BPeekN), but with a heap-allocated buffer, that grows on demand.
+///
+/// Buffer may be given a soft limit, in which case peeking further than that is reported as an error.
+pub struct BPeekDyn<I: Iterator> {
+    inner: I,
+    queue: VecDeque<I::Item>,
+    limit: Option<usize>,
+}
+
+impl<I: Iterator> Debug for BPeekDyn<I>
+where
+    I: Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BPeekDyn")
+            .field("inner", &self.inner)
+            .field("queue", &self.queue)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Clone for BPeekDyn<I>
+where
+    I: Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        BPeekDyn {
+            inner: self.inner.clone(),
+            queue: self.queue.clone(),
+            limit: self.limit,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for BPeekDyn<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.queue.pop_front() {
+            Some(buffered) => Some(buffered),
+            None => self.inner.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.queue.len();
+        let (rest_min, res_max) = self.inner.size_hint();
+        (buffered + rest_min, res_max.map(|v| buffered + v))
+    }
+
+    #[inline]
+    fn count(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.queue.len() + self.inner.count()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        if let Some(inner_last) = self.inner.last() {
+            return Some(inner_last);
+        }
+
+        self.queue.pop_back()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let init = self.queue.into_iter().fold(init, &mut f);
+        self.inner.fold(init, f)
+    }
+}
+
+impl<I: Iterator + DoubleEndedIterator> DoubleEndedIterator for BPeekDyn<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // try inner iterator
+        if let Some(inner_item) = self.inner.next_back() {
+            return Some(inner_item);
+        }
+
+        // try getting from buffer
+        self.queue.pop_back()
+    }
+}
+
+impl<I: Iterator + FusedIterator> FusedIterator for BPeekDyn<I> {}
+
+impl<I: Iterator + ExactSizeIterator> ExactSizeIterator for BPeekDyn<I> {}
+
+impl<I: Iterator> BPeekDyn<I> {
+    /// Wraps the iterator, with no limit on the lookahead.
+    #[inline]
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            queue: VecDeque::new(),
+            limit: None,
+        }
+    }
+
+    /// Wraps the iterator, limiting the lookahead to `limit` elements.
+    #[inline]
+    pub fn with_limit(inner: I, limit: usize) -> Self {
+        Self {
+            inner,
+            queue: VecDeque::new(),
+            limit: Some(limit),
+        }
+    }
+
+    #[inline]
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Fails, if `count` elements exceed the lookahead limit.
+    fn check_limit(&self, count: usize) -> Result<(), CapacityError> {
+        match self.limit {
+            Some(limit) if count > limit => Err(CapacityError {
+                required: count,
+                available: limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Pulls elements from the inner iterator, until at least `count` elements are buffered.
+    ///
+    /// Returns `Ok(false)`, if inner iterator runs out of elements.
+    fn fill_to(&mut self, count: usize) -> Result<bool, CapacityError> {
+        self.check_limit(count)?;
+        while self.queue.len() < count {
+            let Some(item) = self.inner.next() else {
+                return Ok(false);
+            };
+            self.queue.push_back(item);
+        }
+        Ok(true)
+    }
+
+    /// Same as [`BPeekDyn::fill_to`], but up to and including the `n`th element.
+    ///
+    /// Whether it is present, is left for the caller to check.
+    fn fill_past(&mut self, n: usize) -> Result<(), CapacityError> {
+        match n.checked_add(1) {
+            Some(count) => self.fill_to(count).map(drop),
+            // that many elements can never be buffered
+            None => self.check_limit(usize::MAX),
+        }
+    }
+
+    /// Returns a reference to the `n`th upcoming element, counting from zero.
+    ///
+    /// Fails, if it lies beyond the lookahead limit.
+    pub fn peek_nth(&mut self, n: usize) -> Result<Option<&I::Item>, CapacityError> {
+        self.fill_past(n)?;
+        Ok(self.queue.get(n))
+    }
+
+    /// Returns a mutable reference to the `n`th upcoming element, counting from zero.
+    ///
+    /// Fails, if it lies beyond the lookahead limit.
+    pub fn peek_nth_mut(&mut self, n: usize) -> Result<Option<&mut I::Item>, CapacityError> {
+        self.fill_past(n)?;
+        Ok(self.queue.get_mut(n))
+    }
+
+    /// Returns a cursor over `count` upcoming elements.
+    ///
+    /// Returns `Ok(None)` if there are not enough elements, or `count` is zero, since there's no such thing as peeking 0th element.
+    /// Fails, if the elements lie beyond the lookahead limit.
+    pub fn bpeek(&mut self, count: usize) -> Result<Option<BPeekDynCursor<'_, I>>, CapacityError> {
+        if count == 0 {
+            return Ok(None);
+        }
+        Ok(self.fill_to(count)?.then_some(BPeekDynCursor {
+            iter: self,
+            ind: count,
+        }))
+    }
+}
+
+/// Cursor over the elements buffered by [`BPeekDyn`], same as [`PeekCursor`](super::PeekCursor) with runtime-known position.
+pub struct BPeekDynCursor<'iter, I: Iterator> {
+    iter: &'iter mut BPeekDyn<I>,
+    /// Number of peeked elements, always non-zero
+    ind: usize,
+}
+
+impl<I: Iterator> Debug for BPeekDynCursor<'_, I>
+where
+    I: Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BPeekDynCursor")
+            .field("iter", &*self.iter)
+            .field("ind", &self.ind)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Deref for BPeekDynCursor<'_, I> {
+    type Target = I::Item;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.get(self.ind - 1),
+                "Should be present, since number of buffered elements is ensured on construction",
+            )
+        }
+    }
+}
+
+impl<I: Iterator> DerefMut for BPeekDynCursor<'_, I> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.get_mut(self.ind - 1),
+                "Should be present, since number of buffered elements is ensured on construction",
+            )
+        }
+    }
+}
+
+impl<'iter, I: Iterator> BPeekDynCursor<'iter, I> {
+    /// Number of peeked elements.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.ind
+    }
+
+    /// Consumes all of the peeked elements, returning them in order.
+    pub fn take_all(self) -> vec_deque::Drain<'iter, I::Item> {
+        self.iter.queue.drain(..self.ind)
+    }
+
+    /// Returns references to all of the peeked elements, in order.
+    pub fn peek_all(&self) -> vec_deque::Iter<'_, I::Item> {
+        self.iter.queue.range(..self.ind)
+    }
+
+    /// Returns mutable references to all of the peeked elements, in order.
+    pub fn peek_all_mut(&mut self) -> vec_deque::IterMut<'_, I::Item> {
+        self.iter.queue.range_mut(..self.ind)
+    }
+
+    /// Moves the cursor one element back.
+    ///
+    /// Fails, if cursor already points at the first element.
+    pub fn peek_prev(self) -> Result<Self, Self> {
+        if self.ind == 1 {
+            return Err(self);
+        }
+        Ok(Self {
+            iter: self.iter,
+            ind: self.ind - 1,
+        })
+    }
+
+    /// Moves the cursor one element forward.
+    ///
+    /// Fails, if there are no more elements, or the next one lies beyond the lookahead limit.
+    pub fn peek_forward(self) -> Result<Self, Self> {
+        match self.iter.fill_to(self.ind + 1) {
+            Ok(true) => Ok(Self {
+                iter: self.iter,
+                ind: self.ind + 1,
+            }),
+            Ok(false) | Err(_) => Err(self),
+        }
+    }
+}
@@ -0,0 +1,443 @@
+use core::{
+    fmt::Debug,
+    iter::{Chain, FusedIterator, Skip, Take},
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    dequeue::{Capacity, Dequeue, Iter},
+    error::unwrap_invariant,
+};
+
+use super::{BPeekN, Peek};
+
+/// [`BPeekN`], that also remembers the last `M` consumed elements, for looking behind.
+///
+/// Dereferences to [`BPeekN`], so the whole lookahead API is available.
+/// Elements are recorded as they enter the lookahead buffer, so every way of consuming them is accounted for,
+/// and they are cloned into the history, so they have to be [`Clone`].
+/// Changes made to the elements through peeking are not reflected in the history.
+///
+/// Elements put back with [`BPeekHist::unread`] are recorded too, once consumed.
+/// Putting them back through [`BPeekN::unread`] directly bypasses the history.
+pub struct BPeekHist<I: Iterator, N: Capacity, M: Capacity>
+where
+    I::Item: Clone,
+{
+    iter: BPeekN<Recorder<I, N, M>, N>,
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Debug for BPeekHist<I, N, M>
+where
+    I: Debug,
+    I::Item: Clone + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BPeekHist")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Clone for BPeekHist<I, N, M>
+where
+    I: Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        BPeekHist {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Deref for BPeekHist<I, N, M>
+where
+    I::Item: Clone,
+{
+    type Target = BPeekN<Recorder<I, N, M>, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.iter
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> DerefMut for BPeekHist<I, N, M>
+where
+    I::Item: Clone,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.iter
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Iterator for BPeekHist<I, N, M>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
+    }
+}
+
+impl<I: Iterator + FusedIterator, N: Capacity, M: Capacity> FusedIterator for BPeekHist<I, N, M> where
+    I::Item: Clone
+{
+}
+
+impl<I: Iterator + ExactSizeIterator, N: Capacity, M: Capacity> ExactSizeIterator
+    for BPeekHist<I, N, M>
+where
+    I::Item: Clone,
+{
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Peek for BPeekHist<I, N, M>
+where
+    I::Item: Clone,
+{
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        self.iter.peek()
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        self.iter.peek_mut()
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        self.iter.next_if(func)
+    }
+}
+
+/// Iterator adapter, that records the elements it yields, see [`BPeekHist`].
+///
+/// Keeps the last `N + M` yielded elements, since up to `N` of them may still be buffered in front of it.
+pub struct Recorder<I: Iterator, N: Capacity, M: Capacity> {
+    inner: I,
+    /// Most recently yielded elements, possibly still buffered
+    recent: Dequeue<I::Item, N>,
+    /// Elements evicted from `recent`, that are surely consumed
+    older: Dequeue<I::Item, M>,
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Debug for Recorder<I, N, M>
+where
+    I: Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Recorder")
+            .field("inner", &self.inner)
+            .field("recent", &self.recent)
+            .field("older", &self.older)
+            .finish()
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Clone for Recorder<I, N, M>
+where
+    I: Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Recorder {
+            inner: self.inner.clone(),
+            recent: self.recent.clone(),
+            older: self.older.clone(),
+        }
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Iterator for Recorder<I, N, M>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+        if let Some(evicted) = self.recent.push_back_evict(item.clone()) {
+            self.older.push_back_overwrite(evicted);
+        }
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: Iterator + FusedIterator, N: Capacity, M: Capacity> FusedIterator for Recorder<I, N, M> where
+    I::Item: Clone
+{
+}
+
+impl<I: Iterator + ExactSizeIterator, N: Capacity, M: Capacity> ExactSizeIterator
+    for Recorder<I, N, M>
+where
+    I::Item: Clone,
+{
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Recorder<I, N, M> {
+    /// Wraps the iterator, starting with an empty record.
+    #[inline]
+    fn new(inner: I) -> Self {
+        Self {
+            inner,
+            recent: Dequeue::new(),
+            older: Dequeue::new(),
+        }
+    }
+
+    /// Number of recorded elements.
+    #[inline]
+    fn recorded(&self) -> usize {
+        self.older.len() + self.recent.len()
+    }
+
+    /// Recorded elements, oldest first.
+    #[inline]
+    fn iter(&self) -> Chain<Iter<'_, I::Item>, Iter<'_, I::Item>> {
+        self.older.iter().chain(self.recent.iter())
+    }
+
+    /// Returns a reference to the `i`th recorded element, counting from the oldest one.
+    #[inline]
+    fn get(&self, i: usize) -> Option<&I::Item> {
+        match i.checked_sub(self.older.len()) {
+            Some(i) => self.recent.get(i),
+            None => self.older.get(i),
+        }
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> Recorder<I, N, M>
+where
+    I::Item: Clone,
+{
+    /// Records an element put back in front of the `buffered` most recently recorded elements.
+    fn record_unread(&mut self, buffered: usize, item: I::Item) {
+        if self.recent.is_full() {
+            // with fewer than `N` elements buffered, the oldest of them is consumed
+            if let Some(oldest) = self.recent.pop_front() {
+                self.older.push_back_overwrite(oldest);
+            }
+        }
+        let Some(index) = self.recent.len().checked_sub(buffered) else {
+            return;
+        };
+        // can only fail, if the buffer was swapped out from under the record
+        let _ = self.recent.try_insert(index, item);
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity> BPeekHist<I, N, M>
+where
+    I::Item: Clone,
+{
+    /// Wraps the iterator, starting with an empty buffer and history.
+    #[inline]
+    pub fn new(inner: I) -> Self {
+        Self {
+            iter: BPeekN::new(Recorder::new(inner)),
+        }
+    }
+
+    /// Number of recorded elements, that are consumed.
+    #[inline]
+    fn consumed(&self) -> usize {
+        self.iter
+            .inner
+            .recorded()
+            .saturating_sub(self.iter.queue.len())
+    }
+
+    /// Same as [`BPeekN::unread`], but the element is also recorded in the history, once consumed.
+    #[inline]
+    pub fn unread(&mut self, item: I::Item) -> Result<(), I::Item> {
+        let buffered = self.iter.queue.len();
+        self.iter.unread(item.clone())?;
+        self.iter.inner.record_unread(buffered, item);
+        Ok(())
+    }
+
+    /// Same as [`BPeekN::unread_many`], but the elements are also recorded in the history, once consumed.
+    pub fn unread_many<const K: usize>(&mut self, items: [I::Item; K]) -> Result<(), [I::Item; K]> {
+        let buffered = self.iter.queue.len();
+        self.iter.unread_many(items.clone())?;
+        // each is recorded after the previous one, in front of the same buffered elements
+        for item in items {
+            self.iter.inner.record_unread(buffered, item);
+        }
+        Ok(())
+    }
+
+    /// Last `M` consumed elements, oldest first.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn history(&self) -> Skip<Take<Chain<Iter<'_, I::Item>, Iter<'_, I::Item>>>> {
+        let consumed = self.consumed();
+        self.iter
+            .inner
+            .iter()
+            .take(consumed)
+            .skip(consumed.saturating_sub(M::CAPACITY))
+    }
+
+    /// Forgets all of the consumed elements.
+    #[inline]
+    pub fn clear_history(&mut self) {
+        self.iter.inner.older.clear();
+        self.iter.inner.recent.truncate_front(self.iter.queue.len());
+    }
+
+    /// Returns a cursor, pointing at the `K`th most recently consumed element.
+    ///
+    /// Returns `None`, if fewer than `K` elements are remembered.
+    #[inline]
+    pub fn prev<const K: usize>(&self) -> Option<PrevCursor<'_, I, N, M, K>> {
+        let () = PrevCursor::<I, N, M, K>::VALID;
+        let end = self.consumed();
+        if end < K {
+            return None;
+        }
+        Some(PrevCursor {
+            record: &self.iter.inner,
+            end,
+        })
+    }
+
+    #[inline]
+    pub fn prev1(&self) -> Option<PrevCursor<'_, I, N, M, 1>> {
+        self.prev()
+    }
+
+    #[inline]
+    pub fn prev2(&self) -> Option<PrevCursor<'_, I, N, M, 2>> {
+        self.prev()
+    }
+}
+
+/// Cursor over the history of [`BPeekHist`], same as [`PeekCursor`](super::PeekCursor), but looking behind.
+pub struct PrevCursor<'hist, I: Iterator, N: Capacity, M: Capacity, const K: usize> {
+    record: &'hist Recorder<I, N, M>,
+    /// Number of recorded elements, that are consumed
+    end: usize,
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity, const K: usize> Debug for PrevCursor<'_, I, N, M, K>
+where
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PrevCursor")
+            .field("item", &**self)
+            .field("K", &K)
+            .finish()
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity, const K: usize> Clone for PrevCursor<'_, I, N, M, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: Iterator, N: Capacity, M: Capacity, const K: usize> Copy for PrevCursor<'_, I, N, M, K> {}
+
+impl<I: Iterator, N: Capacity, M: Capacity, const K: usize> Deref for PrevCursor<'_, I, N, M, K> {
+    type Target = I::Item;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: number of remembered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.record.get(self.end - K),
+                "Should be present, since number of remembered elements is ensured on construction",
+            )
+        }
+    }
+}
+
+impl<'hist, I: Iterator, N: Capacity, M: Capacity, const K: usize> PrevCursor<'hist, I, N, M, K> {
+    /// Evaluates to a compile error, if the cursor is out of the history bounds.
+    const VALID: () = {
+        assert!(K >= 1, "There's no such thing as looking 0 elements behind");
+        assert!(
+            K <= M::CAPACITY,
+            "Not enough space in the history to look this far behind"
+        );
+    };
+
+    /// Returns references to all of the `K` most recently consumed elements, in the order they were consumed.
+    pub fn peek_all(&self) -> [&'hist I::Item; K] {
+        let record = self.record;
+        let skipped = self.end - K;
+        core::array::from_fn(|i| {
+            // SAFETY: number of remembered elements is ensured on construction
+            unsafe {
+                unwrap_invariant(
+                    record.get(skipped + i),
+                    "Must be present, number of remembered elements is ensured on construction",
+                )
+            }
+        })
+    }
+
+    /// Moves the cursor one element closer to the present.
+    pub fn closer<const CLOSER: usize>(self) -> PrevCursor<'hist, I, N, M, CLOSER> {
+        const {
+            assert!(
+                CLOSER + 1 == K,
+                "Closer cursor must point one element forward"
+            );
+        }
+        let () = PrevCursor::<I, N, M, CLOSER>::VALID;
+        // no checks necessary, all more recent elements are remembered
+        PrevCursor {
+            record: self.record,
+            end: self.end,
+        }
+    }
+
+    /// Moves the cursor one element further into the past.
+    ///
+    /// Fails, if there are no more remembered elements.
+    pub fn further<const FURTHER: usize>(
+        self,
+    ) -> Result<PrevCursor<'hist, I, N, M, FURTHER>, Self> {
+        const {
+            assert!(
+                FURTHER == K + 1,
+                "Further cursor must point one element back"
+            );
+        }
+        let () = PrevCursor::<I, N, M, FURTHER>::VALID;
+        if self.end < FURTHER {
+            return Err(self);
+        }
+        Ok(PrevCursor {
+            record: self.record,
+            end: self.end,
+        })
+    }
+}
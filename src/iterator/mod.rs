@@ -2,26 +2,51 @@ use core::{
     fmt::Debug,
     iter::FusedIterator,
     marker::PhantomData,
-    ops::{Add, Deref, Sub},
+    ops::{Add, Deref, DerefMut, Sub},
 };
 
 use generic_array::{
+    sequence::GenericSequence,
     typenum::{self, Const},
-    ArrayLength, GenericArray, IntoArrayLength,
+    ArrayLength, ConstArrayLength, GenericArray, IntoArrayLength,
 };
 
-use crate::dequeue::Dequeue;
+use crate::{
+    dequeue::{Capacity, ConstCapacity, Dequeue},
+    error::unwrap_invariant,
+    Error,
+};
+
+mod const_generic;
+mod dyn_cursor;
+#[cfg(feature = "alloc")]
+mod growable;
+mod history;
+mod partial;
+mod unread;
+
+pub use const_generic::{BPeek, ConstPeekCursor};
+pub use dyn_cursor::DynPeekCursor;
+#[cfg(feature = "alloc")]
+pub use growable::{BPeekDyn, BPeekDynCursor};
+pub use history::{BPeekHist, PrevCursor, Recorder};
+pub use partial::PartialPeek;
+pub use unread::Unreadable;
 
 type U1 = typenum::U1;
 type U2 = typenum::U2;
 type U3 = typenum::U3;
 
-pub struct BPeekN<I: Iterator, N: ArrayLength> {
+pub struct BPeekN<I: Iterator, N: Capacity> {
     inner: I,
     queue: Dequeue<I::Item, N>,
+    /// Inner iterator has returned `None`, and is not polled anymore
+    exhausted: bool,
+    /// Consuming has run out of elements, so nothing can be unread
+    finished: bool,
 }
 
-impl<I: Iterator, N: ArrayLength> Debug for BPeekN<I, N>
+impl<I: Iterator, N: Capacity> Debug for BPeekN<I, N>
 where
     I: Debug,
     I::Item: Debug,
@@ -30,12 +55,14 @@ where
         f.debug_struct("BPeekN")
             .field("inner", &self.inner)
             .field("queue", &self.queue)
-            .field("LEN", &N::USIZE)
+            .field("exhausted", &self.exhausted)
+            .field("finished", &self.finished)
+            .field("LEN", &N::CAPACITY)
             .finish()
     }
 }
 
-impl<I: Iterator, N: ArrayLength> Clone for BPeekN<I, N>
+impl<I: Iterator, N: Capacity> Clone for BPeekN<I, N>
 where
     I: Clone,
     I::Item: Clone,
@@ -44,24 +71,32 @@ where
         BPeekN {
             inner: self.inner.clone(),
             queue: self.queue.clone(),
+            exhausted: self.exhausted,
+            finished: self.finished,
         }
     }
 }
 
-impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
+impl<I: Iterator, N: Capacity> Iterator for BPeekN<I, N> {
     type Item = I::Item;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         match self.queue.pop_front() {
-            Some(buffered) => Some(buffered),
-            None => self.inner.next(),
+            Some(buffered) => {
+                self.finished = false;
+                Some(buffered)
+            }
+            None => self.consume_inner(Iterator::next),
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         let buffered = self.queue.len();
+        if self.exhausted {
+            return (buffered, Some(buffered));
+        }
         let (rest_min, res_max) = self.inner.size_hint();
         (buffered + rest_min, res_max.map(|v| buffered + v))
     }
@@ -71,7 +106,7 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
     where
         Self: Sized,
     {
-        self.queue.len() + self.inner.count()
+        self.queue.len() + self.live_inner().map_or(0, Iterator::count)
     }
 
     #[inline]
@@ -79,7 +114,7 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
     where
         Self: Sized,
     {
-        if let Some(inner_last) = self.inner.last() {
+        if let Some(inner_last) = self.poll_inner(|inner| inner.last()) {
             return Some(inner_last);
         }
 
@@ -87,19 +122,19 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
     }
 
     #[inline]
-    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
-        if n > self.queue.len() {
-            n -= self.queue.len();
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if let Some(rest) = n.checked_sub(self.queue.len()) {
             self.queue.clear();
-            self.inner.nth(n)
+            self.consume_inner(|inner| inner.nth(rest))
         } else {
-            for _ in 1..n {
-                let _ = self
-                    .queue
-                    .pop_front()
-                    .expect("Must be present, since index of target is less than number of elements in the queue");
-            }
-            Some(self.queue.pop_front().expect("Must be present, since index of target is less than number of elements in the queue"))
+            self.queue.truncate_front(self.queue.len() - n);
+            // SAFETY: index of target is less than number of elements in the queue
+            Some(unsafe {
+                unwrap_invariant(
+                    self.queue.pop_front(),
+                    "Must be present, since index of target is less than number of elements in the queue",
+                )
+            })
         }
     }
 
@@ -112,7 +147,9 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
         while let Some(item) = self.queue.pop_front() {
             f(item);
         }
-        self.inner.for_each(f);
+        if let Some(inner) = self.live_inner() {
+            inner.for_each(f);
+        }
     }
 
     #[inline]
@@ -120,9 +157,9 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
     where
         Self: Sized,
     {
-        core::iter::from_fn(|| self.queue.pop_front())
-            .chain(self.inner)
-            .collect()
+        let queue = core::mem::take(&mut self.queue);
+        let inner = self.live_inner().into_iter().flatten();
+        queue.into_iter().chain(inner).collect()
     }
 
     fn partition<B, F>(mut self, mut f: F) -> (B, B)
@@ -131,16 +168,15 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
         B: Default + Extend<Self::Item>,
         F: FnMut(&Self::Item) -> bool,
     {
+        if N::CAPACITY == 0 {
+            // nothing can be buffered, so there's nothing to batch
+            return self.live_inner().into_iter().flatten().partition(f);
+        }
+
         let mut true_collection = B::default();
         let mut false_collection = B::default();
 
-        for _ in 0..N::USIZE - self.queue.len() {
-            let Some(item) = self.inner.next() else {
-                break;
-            };
-
-            self.queue.push_back(item).assert();
-        }
+        let _ = self.fill_to(N::CAPACITY);
 
         let mut next_result = Option::<bool>::None;
         while !self.queue.is_empty() {
@@ -164,19 +200,17 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
                 };
 
                 collection.extend(core::iter::once(first).chain((0..additional).map(|_| {
-                    self.queue
-                        .pop_front()
-                        .expect("Contains at least as many elements as passed the test")
+                    // SAFETY: elements that passed the test are still in the queue
+                    unsafe {
+                        unwrap_invariant(
+                            self.queue.pop_front(),
+                            "Contains at least as many elements as passed the test",
+                        )
+                    }
                 })));
             }
 
-            for _ in 0..N::USIZE {
-                let Some(item) = self.inner.next() else {
-                    break;
-                };
-
-                self.queue.push_back(item).assert();
-            }
+            let _ = self.fill_to(N::CAPACITY);
         }
 
         (true_collection, false_collection)
@@ -191,23 +225,21 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
         while let Some(item) = self.queue.pop_front() {
             init = f(init, item);
         }
-        self.inner.fold(init, f)
+        match self.live_inner() {
+            Some(inner) => inner.fold(init, f),
+            None => init,
+        }
     }
 
     #[inline]
-    fn reduce<F>(mut self, mut f: F) -> Option<Self::Item>
+    fn reduce<F>(mut self, f: F) -> Option<Self::Item>
     where
         Self: Sized,
         F: FnMut(Self::Item, Self::Item) -> Self::Item,
     {
-        if let Some(mut res) = self.queue.pop_front() {
-            while let Some(item) = self.queue.pop_front() {
-                res = f(res, item);
-            }
-            Some(self.inner.fold(res, f))
-        } else {
-            self.inner.reduce(f)
-        }
+        let queue = core::mem::take(&mut self.queue);
+        let inner = self.live_inner().into_iter().flatten();
+        queue.into_iter().chain(inner).reduce(f)
     }
 
     #[inline]
@@ -221,7 +253,9 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
                 return false;
             };
         }
-        self.inner.all(f)
+        // all of the elements are consumed, if the predicate holds
+        self.consume_inner(|inner| (!inner.all(f)).then_some(false))
+            .unwrap_or(true)
     }
 
     #[inline]
@@ -235,12 +269,9 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
                 return true;
             };
         }
-        for item in self.inner.by_ref() {
-            if f(item) {
-                return true;
-            };
-        }
-        self.inner.any(f)
+        // all of the elements are consumed, if the predicate does not hold
+        self.consume_inner(|inner| inner.any(f).then_some(true))
+            .unwrap_or(false)
     }
 
     #[inline]
@@ -255,7 +286,7 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
             };
         }
 
-        self.inner.find(predicate)
+        self.consume_inner(|inner| inner.find(predicate))
     }
 
     #[inline]
@@ -270,7 +301,7 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
             };
         }
 
-        self.inner.find_map(f)
+        self.consume_inner(|inner| inner.find_map(f))
     }
 
     #[inline]
@@ -286,61 +317,244 @@ impl<I: Iterator, N: ArrayLength> Iterator for BPeekN<I, N> {
             }
             skipped += 1;
         }
-        self.inner
-            .position(predicate)
+        self.consume_inner(|inner| inner.position(predicate))
             .map(|pos_inner| skipped + pos_inner)
     }
 
     // TODO: probably add rest of the methods
 }
 
-impl<I: Iterator + DoubleEndedIterator, N: ArrayLength> DoubleEndedIterator for BPeekN<I, N> {
+impl<I: Iterator + DoubleEndedIterator, N: Capacity> DoubleEndedIterator for BPeekN<I, N> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         // try inner iterator
-        if let Some(inner_item) = self.inner.next_back() {
+        if let Some(inner_item) = self.poll_inner(DoubleEndedIterator::next_back) {
             return Some(inner_item);
         }
 
         // try getting from buffer
-        self.queue.pop_back()
+        let res = self.queue.pop_back();
+        self.finished = res.is_none();
+        res
     }
 }
 
-impl<I: Iterator + FusedIterator, N: ArrayLength> FusedIterator for BPeekN<I, N> {}
+impl<I: Iterator + FusedIterator, N: Capacity> FusedIterator for BPeekN<I, N> {}
 
-impl<I: Iterator + ExactSizeIterator, N: ArrayLength> ExactSizeIterator for BPeekN<I, N> {}
+impl<I: Iterator + ExactSizeIterator, N: Capacity> ExactSizeIterator for BPeekN<I, N> {}
 
-impl<I: Iterator, N: ArrayLength> BPeekN<I, N> {
-    fn ensure_elements<C: ArrayLength>(&mut self) -> Option<GenericArray<&I::Item, C>>
+impl<I: Iterator, N: Capacity> BPeekN<I, N> {
+    /// Wraps the iterator, starting with an empty buffer.
+    #[inline]
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            queue: Dequeue::new(),
+            exhausted: false,
+            finished: false,
+        }
+    }
+
+    /// Wraps the iterator, with elements already buffered in front of it.
+    ///
+    /// Reverse of [`BPeekN::into_parts`], can be used to restore a previously saved buffer.
+    #[inline]
+    pub fn from_parts(inner: I, buffer: Dequeue<I::Item, N>) -> Self {
+        Self {
+            inner,
+            queue: buffer,
+            exhausted: false,
+            finished: false,
+        }
+    }
+
+    /// Splits into the inner iterator, and elements buffered in front of it.
+    #[inline]
+    pub fn into_parts(self) -> (I, Dequeue<I::Item, N>) {
+        (self.inner, self.queue)
+    }
+
+    /// Elements buffered in front of the inner iterator.
+    #[inline]
+    pub fn buffer(&self) -> &Dequeue<I::Item, N> {
+        &self.queue
+    }
+
+    /// Elements buffered in front of the inner iterator, as a pair of slices in order.
+    ///
+    /// Second slice is empty, unless buffered elements wrap around the end of the underlying array.
+    #[inline]
+    pub fn buffered(&self) -> (&[I::Item], &[I::Item]) {
+        self.queue.as_slices()
+    }
+
+    /// Pulls elements from the inner iterator, until the buffer is full or it runs out, and returns the buffered elements.
+    ///
+    /// See [`BPeekN::buffered`].
+    #[inline]
+    pub fn fill(&mut self) -> (&[I::Item], &[I::Item]) {
+        let _ = self.fill_to(N::CAPACITY);
+        self.queue.as_slices()
+    }
+
+    /// Evaluates to a compile error, if the buffer can't hold a single element.
+    const CAN_PEEK: () = assert!(
+        N::CAPACITY >= 1,
+        "Not enough space in the buffer to peek this far"
+    );
+
+    /// Returns a reference to the next element, without consuming it.
+    ///
+    /// Same as [`Peekable::peek`](core::iter::Peekable::peek). Buffer must be able to hold at least one element, that is checked at compile time.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        let () = Self::CAN_PEEK;
+        self.fill_to(1).ok()?;
+        self.queue.get(0)
+    }
+
+    /// Returns a mutable reference to the next element, without consuming it.
+    ///
+    /// Same as [`Peekable::peek_mut`](core::iter::Peekable::peek_mut). Buffer must be able to hold at least one element, that is checked at compile time.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        let () = Self::CAN_PEEK;
+        self.fill_to(1).ok()?;
+        self.queue.get_mut(0)
+    }
+
+    /// Consumes and returns the next element, if it satisfies the predicate.
+    ///
+    /// Same as [`Peekable::next_if`](core::iter::Peekable::next_if), element is kept buffered otherwise.
+    #[inline]
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        if func(self.peek()?) {
+            self.queue.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Consumes and returns the next element, if it is equal to `expected`.
+    ///
+    /// Same as [`Peekable::next_if_eq`](core::iter::Peekable::next_if_eq).
+    #[inline]
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
     where
-        N: Sub<C>,
+        T: ?Sized,
+        I::Item: PartialEq<T>,
     {
-        if self.queue.len() < C::USIZE {
-            for _ in 0..C::USIZE - self.queue.len() {
-                self.queue.push_back(self.inner.next()?).assert();
-                // ^^ always able to push, since number of elements to ensure is statically proven to not be larger than number of elements buffer can hold
+        self.next_if(|next| next == expected)
+    }
+
+    /// Whether the inner iterator has returned `None`.
+    ///
+    /// Once it has, it is not polled anymore, even if it is not fused, until [`BPeekN::resume`] is called.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Polls the inner iterator again, even if it has returned `None`.
+    ///
+    /// Needed for sources, that yield more elements later, such as [`Consumer`](crate::spsc::Consumer) once producer pushes them.
+    #[inline]
+    pub fn resume(&mut self) {
+        self.exhausted = false;
+        self.finished = false;
+    }
+
+    /// Inner iterator, unless it is exhausted.
+    #[inline]
+    fn live_inner(self) -> Option<I> {
+        (!self.exhausted).then_some(self.inner)
+    }
+
+    /// Same as [`BPeekN::poll_inner`], but for consuming, once the buffer is empty.
+    ///
+    /// Running out of elements means, that nothing can be unread anymore.
+    #[inline]
+    fn consume_inner<T>(&mut self, poll: impl FnOnce(&mut I) -> Option<T>) -> Option<T> {
+        debug_assert!(self.queue.is_empty());
+        let res = self.poll_inner(poll);
+        self.finished = res.is_none();
+        res
+    }
+
+    /// Polls the inner iterator with `poll`, unless it is exhausted.
+    ///
+    /// `poll` returning `None` means, that inner iterator is exhausted.
+    #[inline]
+    fn poll_inner<T>(&mut self, poll: impl FnOnce(&mut I) -> Option<T>) -> Option<T> {
+        if self.exhausted {
+            return None;
+        }
+        let res = poll(&mut self.inner);
+        self.exhausted = res.is_none();
+        res
+    }
+
+    /// Pulls elements from the inner iterator, until at least `count` elements are buffered.
+    ///
+    /// Fails, if inner iterator runs out of elements first.
+    /// Caller must ensure, that `count` does not exceed the capacity.
+    fn fill_to(&mut self, count: usize) -> Result<(), Error> {
+        debug_assert!(count <= N::CAPACITY);
+        while self.queue.len() < count {
+            let Some(item) = self.poll_inner(Iterator::next) else {
+                return Err(Error::Exhausted {
+                    available: self.queue.len(),
+                });
+            };
+            // SAFETY: number of elements to ensure is not larger than number of elements buffer can hold
+            unsafe {
+                unwrap_invariant(
+                    self.queue.push_back(item).into_result().ok(),
+                    "Always able to push, since buffer is not filled past the capacity",
+                );
             }
         }
+        Ok(())
+    }
+}
 
-        Some(
-            (0..C::USIZE)
-                .map(|i| {
-                    self.queue.get(i).expect(
-                        "Rest of the function proves that this element exists in the buffer",
-                    )
-                })
-                .collect(),
-        )
+impl<I: Iterator, N: ArrayLength> BPeekN<I, N> {
+    fn ensure_elements<C: ArrayLength>(&mut self) -> Result<GenericArray<&I::Item, C>, Error>
+    where
+        N: Sub<C>,
+    {
+        self.fill_to(C::USIZE)?;
+        // ^^ number of elements to ensure is statically proven to not be larger than number of elements buffer can hold
+
+        Ok(GenericArray::generate(|i| {
+            // SAFETY: buffer was just filled with at least `C` elements
+            unsafe {
+                unwrap_invariant(
+                    self.queue.get(i),
+                    "Rest of the function proves that this element exists in the buffer",
+                )
+            }
+        }))
     }
 
     #[inline]
     pub fn bpeek<Off: ArrayLength + Sub<U1>>(&mut self) -> Option<PeekCursor<'_, I, N, Off>>
+    where
+        N: Sub<Off>,
+    {
+        self.try_bpeek().ok()
+    }
+
+    /// Same as [`BPeekN::bpeek`], but reports how many elements are available, if there's not enough of them.
+    #[inline]
+    pub fn try_bpeek<Off: ArrayLength + Sub<U1>>(
+        &mut self,
+    ) -> Result<PeekCursor<'_, I, N, Off>, Error>
     where
         N: Sub<Off>,
     {
         let _ = self.ensure_elements::<Off>()?;
-        Some(PeekCursor {
+        Ok(PeekCursor {
             iter: self,
             _phantom: PhantomData,
         })
@@ -369,6 +583,44 @@ impl<I: Iterator, N: ArrayLength> BPeekN<I, N> {
     {
         self.bpeek()
     }
+
+    /// Consumes and returns the next `K` elements, if all of them together satisfy the predicate.
+    ///
+    /// If there are less than `K` elements, or predicate fails, nothing is consumed.
+    pub fn next_if_seq<const K: usize>(
+        &mut self,
+        pred: impl FnOnce(&[&I::Item; K]) -> bool,
+    ) -> Option<[I::Item; K]>
+    where
+        Const<K>: IntoArrayLength,
+        ConstArrayLength<K>: Sub<U1>,
+        N: Sub<ConstArrayLength<K>>,
+    {
+        let cursor = self.bpeek::<ConstArrayLength<K>>()?;
+        if !pred(&cursor.peek_all()) {
+            return None;
+        }
+        Some(cursor.take_all())
+    }
+
+    /// Consumes and returns the next `K` elements, if they are equal to `expected`.
+    ///
+    /// If there are less than `K` elements, or they are not equal, nothing is consumed.
+    #[inline]
+    pub fn next_if_eq_seq<T, const K: usize>(&mut self, expected: &[T; K]) -> Option<[I::Item; K]>
+    where
+        I::Item: PartialEq<T>,
+        Const<K>: IntoArrayLength,
+        ConstArrayLength<K>: Sub<U1>,
+        N: Sub<ConstArrayLength<K>>,
+    {
+        self.next_if_seq(|peeked| {
+            peeked
+                .iter()
+                .zip(expected)
+                .all(|(item, expected)| *item == expected)
+        })
+    }
 }
 
 pub struct PeekCursor<'iter, I: Iterator, N: ArrayLength + Sub<Ind>, Ind: ArrayLength + Sub<U1>> {
@@ -396,47 +648,81 @@ where
     type Target = I::Item;
 
     fn deref(&self) -> &Self::Target {
-        self.iter.queue.get(Ind::USIZE - 1).expect(
-            "Should be present, since number of buffered elements is ensured on construction",
-        )
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.get(Ind::USIZE - 1),
+                "Should be present, since number of buffered elements is ensured on construction",
+            )
+        }
+    }
+}
+
+impl<I: Iterator, N: ArrayLength, Ind: ArrayLength + Sub<U1>> DerefMut for PeekCursor<'_, I, N, Ind>
+where
+    N: Sub<Ind>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.get_mut(Ind::USIZE - 1),
+                "Should be present, since number of buffered elements is ensured on construction",
+            )
+        }
     }
 }
 
 impl<I: Iterator, N: ArrayLength + Sub<Ind>, Ind: ArrayLength + Sub<U1>> PeekCursor<'_, I, N, Ind> {
-    ///
+    /// Consumes all of the peeked elements, returning them in order.
     pub fn take_all<const OFF: usize>(self) -> [I::Item; OFF]
     where
         Const<OFF>: IntoArrayLength<ArrayLength = Ind>,
     {
-        let array: GenericArray<I::Item, Ind> = (0..Ind::USIZE)
-            .map(|_| {
-                self.iter
-                    .queue
-                    .pop_front()
-                    .expect("Must be present, number of available elements is ensured statically")
-            })
-            .collect();
-        array.into_array()
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.pop_front_array(),
+                "Must be present, number of available elements is ensured statically",
+            )
+        }
     }
 }
 
 impl<'iter, I: Iterator, N: ArrayLength + Sub<Ind>, Ind: ArrayLength + Sub<U1>>
     PeekCursor<'iter, I, N, Ind>
 {
-    ///
+    /// Returns references to all of the peeked elements, in order.
     pub fn peek_all<const OFF: usize>(&self) -> [&I::Item; OFF]
     where
         Const<OFF>: IntoArrayLength<ArrayLength = Ind>,
     {
-        let array: GenericArray<&I::Item, Ind> = (0..Ind::USIZE)
-            .map(|i| {
+        // `OFF` is statically the same number as `Ind`
+        core::array::from_fn(|i| {
+            // SAFETY: number of buffered elements is ensured on construction
+            unsafe {
+                unwrap_invariant(
+                    self.iter.queue.get(i),
+                    "Must be present, number of available elements is ensured statically",
+                )
+            }
+        })
+    }
+
+    /// Returns mutable references to all of the peeked elements, in order.
+    pub fn peek_all_mut<const OFF: usize>(&mut self) -> [&mut I::Item; OFF]
+    where
+        Const<OFF>: IntoArrayLength<ArrayLength = Ind>,
+    {
+        // SAFETY: number of buffered elements is ensured on construction, and indices are distinct
+        unsafe {
+            unwrap_invariant(
                 self.iter
                     .queue
-                    .get(i)
-                    .expect("Must be present, number of available elements is ensured statically")
-            })
-            .collect();
-        array.into_array()
+                    .get_disjoint_mut(core::array::from_fn(|i| i)),
+                "Must be present, number of available elements is ensured statically",
+            )
+        }
     }
 
     pub fn peek_prev(self) -> PeekCursor<'iter, I, N, <Ind as Sub<U1>>::Output>
@@ -463,11 +749,16 @@ impl<'iter, I: Iterator, N: ArrayLength + Sub<Ind>, Ind: ArrayLength + Sub<U1>>
                 Ind::USIZE,
                 "At this point, number of buffered elements can only be 1 less"
             );
-            let Some(last_item) = self.iter.inner.next() else {
+            let Some(last_item) = self.iter.poll_inner(Iterator::next) else {
                 return Err(self);
             };
-            self.iter.queue.push_back(last_item).assert();
-            // ^^^ must be able to push, buffer capacity is ensured statically
+            // SAFETY: buffer capacity is ensured statically
+            unsafe {
+                unwrap_invariant(
+                    self.iter.queue.push_back(last_item).into_result().ok(),
+                    "Must be able to push, buffer capacity is ensured statically",
+                );
+            }
         }
         debug_assert_eq!(
             self.iter.queue.len(),
@@ -481,13 +772,91 @@ impl<'iter, I: Iterator, N: ArrayLength + Sub<Ind>, Ind: ArrayLength + Sub<U1>>
     }
 }
 
+/// Single-element peeking interface, shared by [`Peekable`](core::iter::Peekable) and [`BPeekN`].
+///
+/// Allows generic code to accept either one.
+pub trait Peek: Iterator {
+    /// See [`Peekable::peek`](core::iter::Peekable::peek).
+    fn peek(&mut self) -> Option<&Self::Item>;
+
+    /// See [`Peekable::peek_mut`](core::iter::Peekable::peek_mut).
+    fn peek_mut(&mut self) -> Option<&mut Self::Item>;
+
+    /// See [`Peekable::next_if`](core::iter::Peekable::next_if).
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item>;
+
+    /// See [`Peekable::next_if_eq`](core::iter::Peekable::next_if_eq).
+    #[inline]
+    fn next_if_eq<T>(&mut self, expected: &T) -> Option<Self::Item>
+    where
+        T: ?Sized,
+        Self::Item: PartialEq<T>,
+    {
+        self.next_if(|next| next == expected)
+    }
+}
+
+impl<I: Iterator> Peek for core::iter::Peekable<I> {
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        core::iter::Peekable::peek(self)
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        core::iter::Peekable::peek_mut(self)
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        core::iter::Peekable::next_if(self, func)
+    }
+}
+
+/// Buffer must be able to hold at least one element, that is checked at compile time.
+impl<I: Iterator, N: Capacity> Peek for BPeekN<I, N> {
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        BPeekN::peek(self)
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        BPeekN::peek_mut(self)
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        BPeekN::next_if(self, func)
+    }
+}
+
 pub trait BPeekExt: Iterator + Sized {
     #[inline]
     fn bpeekable<N: ArrayLength>(self) -> BPeekN<Self, N> {
-        BPeekN {
-            inner: self,
-            queue: Dequeue::new(),
-        }
+        BPeekN::new(self)
+    }
+
+    /// Same as [`BPeekExt::bpeekable`], but with capacity specified as a const generic.
+    #[inline]
+    fn bpeekable_const<const N: usize>(self) -> BPeek<Self, N> {
+        BPeekN::<Self, ConstCapacity<N>>::new(self)
+    }
+
+    /// Same as [`BPeekExt::bpeekable`], but with a growable heap-allocated buffer.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn bpeekable_dyn(self) -> BPeekDyn<Self> {
+        BPeekDyn::new(self)
+    }
+
+    /// Same as [`BPeekExt::bpeekable`], but also remembering the last `M` consumed elements.
+    #[inline]
+    fn bpeekable_hist<N: ArrayLength, M: ArrayLength>(self) -> BPeekHist<Self, N, M>
+    where
+        Self::Item: Clone,
+    {
+        BPeekHist::new(self)
     }
 
     #[inline]
@@ -0,0 +1,106 @@
+use core::{fmt::Debug, iter::Take};
+
+use crate::{
+    dequeue::{Capacity, Drain, Iter, IterMut},
+    error::unwrap_invariant,
+};
+
+use super::BPeekN;
+
+impl<I: Iterator, N: Capacity> BPeekN<I, N> {
+    /// Peeks up to `K` upcoming elements, settling for less at the end of input.
+    ///
+    /// Unlike [`BPeekN::bpeek`], never fails: the number of elements actually available is reported by [`PartialPeek::count`].
+    #[inline]
+    pub fn bpeek_at_most<const K: usize>(&mut self) -> PartialPeek<'_, I, N, K> {
+        const {
+            assert!(
+                K <= N::CAPACITY,
+                "Not enough space in the buffer to peek this far"
+            );
+        }
+        // running out of elements is fine, whatever is buffered is peeked
+        let _ = self.fill_to(K);
+        let count = self.queue.len().min(K);
+        PartialPeek { iter: self, count }
+    }
+}
+
+/// Up to `K` upcoming elements of [`BPeekN`], see [`BPeekN::bpeek_at_most`].
+pub struct PartialPeek<'iter, I: Iterator, N: Capacity, const K: usize> {
+    iter: &'iter mut BPeekN<I, N>,
+    /// Number of peeked elements, at most `K`
+    count: usize,
+}
+
+impl<I: Iterator, N: Capacity, const K: usize> Debug for PartialPeek<'_, I, N, K>
+where
+    I: Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PartialPeek")
+            .field("iter", &*self.iter)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<'iter, I: Iterator, N: Capacity, const K: usize> PartialPeek<'iter, I, N, K> {
+    /// Number of peeked elements, in range `0..=K`.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Whether all of the requested `K` elements are available.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.count == K
+    }
+
+    /// Whether no elements are available at all.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns a reference to the `i`th peeked element, counting from zero.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&I::Item> {
+        if i >= self.count {
+            return None;
+        }
+        self.iter.queue.get(i)
+    }
+
+    /// Returns a mutable reference to the `i`th peeked element, counting from zero.
+    #[inline]
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut I::Item> {
+        if i >= self.count {
+            return None;
+        }
+        self.iter.queue.get_mut(i)
+    }
+
+    /// Consumes all of the peeked elements, returning them in order.
+    pub fn take_all(self) -> Drain<'iter, I::Item, N> {
+        // SAFETY: number of buffered elements is ensured on construction
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.try_drain(..self.count).ok(),
+                "Must be present, number of available elements is ensured on construction",
+            )
+        }
+    }
+
+    /// Returns references to all of the peeked elements, in order.
+    pub fn peek_all(&self) -> Take<Iter<'_, I::Item>> {
+        self.iter.queue.iter().take(self.count)
+    }
+
+    /// Returns mutable references to all of the peeked elements, in order.
+    pub fn peek_all_mut(&mut self) -> Take<IterMut<'_, I::Item>> {
+        self.iter.queue.iter_mut().take(self.count)
+    }
+}
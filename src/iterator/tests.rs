@@ -26,9 +26,50 @@ macro_rules! test_enforce_same_behavior {
         // assert
         assert_eq!(normal_result, peek_result);
     }};
+    (@const $iter:expr, $N:literal, $var:ident $body:block) => {{
+        // arrange
+        let normal_iter = ($iter);
+        let peek_iter: BPeek<_, $N> = ($iter).bpeekable_const::<$N>();
+
+        // act
+        let normal_result = {
+            #[allow(unused_mut)]
+            let mut $var = normal_iter;
+            $body
+        };
+        let peek_result = {
+            #[allow(unused_mut)]
+            let mut $var = peek_iter;
+            $body
+        };
+
+        // assert
+        assert_eq!(normal_result, peek_result);
+    }};
+    (@dyn $iter:expr, $var:ident $body:block) => {{
+        // arrange
+        let normal_iter = ($iter);
+        let peek_iter: BPeekDyn<_> = ($iter).bpeekable_dyn();
+
+        // act
+        let normal_result = {
+            #[allow(unused_mut)]
+            let mut $var = normal_iter;
+            $body
+        };
+        let peek_result = {
+            #[allow(unused_mut)]
+            let mut $var = peek_iter;
+            $body
+        };
+
+        // assert
+        assert_eq!(normal_result, peek_result);
+    }};
     ($name:ident, $iter:expr, $var:ident $body:block) => {
         #[test]
         fn $name() {
+            test_enforce_same_behavior!(@ $iter, typenum::U<0>, $var $body);
             test_enforce_same_behavior!(@ $iter, typenum::U<1>, $var $body);
             test_enforce_same_behavior!(@ $iter, typenum::U<2>, $var $body);
             test_enforce_same_behavior!(@ $iter, typenum::U<3>, $var $body);
@@ -42,6 +83,14 @@ macro_rules! test_enforce_same_behavior {
             test_enforce_same_behavior!(@ $iter, typenum::U<10>, $var $body);
 
             test_enforce_same_behavior!(@ $iter, typenum::U<42>, $var $body);
+
+            test_enforce_same_behavior!(@const $iter, 0, $var $body);
+            test_enforce_same_behavior!(@const $iter, 1, $var $body);
+            test_enforce_same_behavior!(@const $iter, 3, $var $body);
+            test_enforce_same_behavior!(@const $iter, 42, $var $body);
+
+            #[cfg(feature = "alloc")]
+            test_enforce_same_behavior!(@dyn $iter, $var $body);
         }
     };
 }
@@ -66,6 +115,10 @@ test_enforce_same_behavior!(size_next, 1..=5, iter {
     (iter.size_hint(), iter.next(), iter.size_hint(), iter.next_back(), iter.size_hint(), iter.next(), iter.next_back(), iter.size_hint())
 });
 
+test_enforce_same_behavior!(zero_sized, core::iter::repeat_n((), 50), iter {
+    (iter.next(), iter.nth(3), iter.size_hint(), iter.partition::<Vec<_>, _>(|_| true))
+});
+
 test_enforce_same_behavior!(same_count, 0..100, iter {
     iter.count()
 });
@@ -166,3 +219,521 @@ fn peek() {
         "There are not enough elements left"
     );
 }
+
+#[test]
+fn peek_const() {
+    let normal_iter = 0..5;
+    let mut peeked_iter = normal_iter.bpeekable_const::<3>();
+
+    let peek_1 = peeked_iter.bpeek1().expect("Must have a 1st element");
+    assert_eq!(*peek_1, 0);
+    // let peek0 = peek1.peek_prev::<0>(); // <-- does not compile, there's no such thing as peeking 0th element
+    let peek_12 = peek_1.peek_forward::<2>().expect("Must have a 2nd element");
+    assert_eq!(*peek_12, 1);
+    let peek_123 = peek_12
+        .peek_forward::<3>()
+        .expect("Must have a 3rd element");
+    // let peek4 = peek3.peek_forward::<4>(); // <-- does not compile, not enough space to store 4 elements
+    assert_eq!([&0, &1, &2], peek_123.peek_all());
+    let peek_12 = peek_123.peek_prev::<2>(); // no need for unwrap
+    assert_eq!([&0, &1], peek_12.peek_all());
+    assert_eq!([0, 1], peek_12.take_all());
+
+    let peek_345 = peeked_iter
+        .bpeek::<3>()
+        .expect("Must have 3rd, 4th and 5th elements");
+    assert_eq!([&2, &3, &4], peek_345.peek_all());
+    let peek3 = peek_345.peek_prev::<2>().peek_prev::<1>();
+    assert_eq!([2], peek3.take_all());
+
+    assert_eq!(
+        Some([3]),
+        peeked_iter.bpeek1().map(ConstPeekCursor::take_all)
+    );
+    assert!(
+        peeked_iter.bpeek3().is_none(),
+        "There are not enough elements left"
+    );
+    assert!(
+        peeked_iter.bpeek2().is_none(),
+        "There are not enough elements left"
+    );
+    let peek_5 = peeked_iter.bpeek1().expect("Must have 5th element");
+    assert_eq!([4], peek_5.take_all());
+
+    assert_eq!(None, peeked_iter.next());
+    assert!(
+        peeked_iter.bpeek3().is_none(),
+        "There are not enough elements left"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn peek_dyn() {
+    let normal_iter = 0..5;
+    let mut peeked_iter = normal_iter.bpeekable_dyn();
+
+    assert_eq!(peeked_iter.peek_nth(4), Ok(Some(&4)));
+    assert_eq!(peeked_iter.peek_nth(5), Ok(None));
+    *peeked_iter.peek_nth_mut(0).unwrap().unwrap() = 10;
+
+    let peek_1 = peeked_iter
+        .bpeek(1)
+        .unwrap()
+        .expect("Must have a 1st element");
+    assert_eq!(*peek_1, 10);
+    let peek_1 = peek_1.peek_prev().expect_err("There's no 0th element");
+    let peek_12 = peek_1.peek_forward().expect("Must have a 2nd element");
+    assert_eq!(*peek_12, 1);
+    assert!(peek_12.peek_all().eq(&[10, 1]));
+    assert!(peek_12.take_all().eq([10, 1]));
+
+    let peek_345 = peeked_iter
+        .bpeek(3)
+        .unwrap()
+        .expect("Must have 3 more elements");
+    assert_eq!(peek_345.count(), 3);
+    let peek_345 = peek_345
+        .peek_forward()
+        .expect_err("There are not enough elements left");
+    assert!(peek_345.take_all().eq(2..5));
+
+    assert!(peeked_iter.bpeek(0).unwrap().is_none());
+    assert!(peeked_iter.bpeek(1).unwrap().is_none());
+    assert_eq!(None, peeked_iter.next());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn peek_dyn_limit() {
+    let mut peeked_iter = BPeekDyn::with_limit(0..100, 3);
+
+    assert_eq!(peeked_iter.peek_nth(2), Ok(Some(&2)));
+    assert_eq!(
+        peeked_iter.peek_nth(3),
+        Err(crate::CapacityError {
+            required: 4,
+            available: 3
+        })
+    );
+
+    let peek_123 = peeked_iter.bpeek(3).unwrap().unwrap();
+    assert!(
+        peek_123.peek_forward().is_err(),
+        "Can not peek beyond the limit"
+    );
+    assert!(peeked_iter.bpeek(4).is_err());
+    assert_eq!(peeked_iter.next(), Some(0));
+
+    // does not overflow
+    assert_eq!(
+        peeked_iter.peek_nth(usize::MAX),
+        Err(crate::CapacityError {
+            required: usize::MAX,
+            available: 3
+        })
+    );
+    let mut unlimited = BPeekDyn::new(0..3);
+    assert_eq!(unlimited.peek_nth(usize::MAX), Ok(None));
+    assert_eq!(unlimited.peek_nth_mut(usize::MAX), Ok(None));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn restore_buffer() {
+    use serde_test::{assert_tokens, Token};
+
+    let mut peeked_iter = (0..5).bpeekable3();
+    assert_eq!([&0, &1], peeked_iter.bpeek2().unwrap().peek_all());
+
+    assert_tokens(
+        peeked_iter.buffer(),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::I32(0),
+            Token::I32(1),
+            Token::SeqEnd,
+        ],
+    );
+
+    let (inner, buffer) = peeked_iter.into_parts();
+    let restored = BPeekN::from_parts(inner, buffer);
+    assert!(restored.eq(0..5));
+}
+
+#[test]
+#[allow(clippy::iter_nth_zero)] // `nth(0)` is exactly what is tested
+fn nth_buffered() {
+    let mut iter = (0..10).bpeekable::<typenum::U<4>>();
+    assert_eq!(iter.bpeek3().as_deref(), Some(&2));
+    assert_eq!(iter.nth(0), Some(0));
+    // `1` and `2` are buffered
+    assert_eq!(iter.nth(1), Some(2));
+    assert_eq!(iter.bpeek2().as_deref(), Some(&4));
+    // `3` and `4` are buffered, `5` is not
+    assert_eq!(iter.nth(2), Some(5));
+    assert_eq!(iter.collect::<Vec<_>>(), [6, 7, 8, 9]);
+
+    let mut pass_through = (0..10).bpeekable::<typenum::U<0>>();
+    assert_eq!(pass_through.nth(0), Some(0));
+    assert_eq!(pass_through.nth(2), Some(3));
+    let (even, odd) = pass_through.partition::<Vec<_>, _>(|v| v % 2 == 0);
+    assert_eq!(
+        (even.as_slice(), odd.as_slice()),
+        (&[4, 6, 8][..], &[5, 7, 9][..])
+    );
+}
+
+#[test]
+fn try_bpeek() {
+    let mut iter = (0..2).bpeekable::<typenum::U<3>>();
+    assert_eq!(iter.try_bpeek::<typenum::U<2>>().as_deref(), Ok(&1));
+    assert_eq!(
+        iter.try_bpeek::<typenum::U<3>>().err(),
+        Some(crate::Error::Exhausted { available: 2 })
+    );
+
+    let mut iter = (0..2).bpeekable_const::<3>();
+    assert_eq!(iter.try_bpeek::<1>().as_deref(), Ok(&0));
+    assert_eq!(
+        iter.try_bpeek::<3>().err(),
+        Some(crate::Error::Exhausted { available: 2 })
+    );
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1]);
+}
+
+#[test]
+fn peek_mut() {
+    let mut iter = (0..5).bpeekable::<typenum::U<3>>();
+    *iter.peek_mut().unwrap() += 10;
+    *iter.bpeek2().unwrap() += 20;
+    let mut cursor = iter.bpeek3().unwrap();
+    for item in cursor.peek_all_mut::<3>() {
+        *item *= 2;
+    }
+    assert_eq!(cursor.take_all::<3>(), [20, 42, 4]);
+    assert_eq!(iter.collect::<Vec<_>>(), [3, 4]);
+
+    let mut iter = (0..3).bpeekable_const::<2>();
+    *iter.peek_mut().unwrap() += 10;
+    let mut cursor = iter.bpeek::<2>().unwrap();
+    *cursor += 20;
+    let [first, second] = cursor.peek_all_mut();
+    core::mem::swap(first, second);
+    assert_eq!(iter.collect::<Vec<_>>(), [21, 10, 2]);
+
+    let mut iter = core::iter::empty::<u8>().bpeekable1();
+    assert_eq!(iter.peek_mut(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn peek_dyn_mut() {
+    let mut iter = (0..3).bpeekable_dyn();
+    let mut cursor = iter.bpeek(2).unwrap().unwrap();
+    *cursor += 10;
+    cursor.peek_all_mut().for_each(|item| *item *= 2);
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 22, 2]);
+}
+
+/// Parses a run of digits, followed by an optional `+`.
+fn parse_number(iter: &mut impl Peek<Item = char>) -> (u32, bool) {
+    let mut number = 0;
+    while let Some(digit) = iter.next_if(char::is_ascii_digit) {
+        number = number * 10 + digit.to_digit(10).unwrap();
+    }
+    if let Some(next) = iter.peek_mut() {
+        next.make_ascii_uppercase();
+    }
+    (number, iter.next_if_eq(&'+').is_some())
+}
+
+#[test]
+fn peekable_compat() {
+    let input = "12+34x+5";
+    let mut peekable = input.chars().peekable();
+    let mut bpeek = input.chars().bpeekable1();
+    let mut bpeek_const = input.chars().bpeekable_const::<2>();
+
+    for _ in 0..3 {
+        let expected = parse_number(&mut peekable);
+        assert_eq!(parse_number(&mut bpeek), expected);
+        assert_eq!(parse_number(&mut bpeek_const), expected);
+
+        let expected = peekable.next();
+        assert_eq!(bpeek.next(), expected);
+        assert_eq!(bpeek_const.next(), expected);
+    }
+    assert_eq!(bpeek.peek(), None);
+    assert_eq!(bpeek_const.peek(), None);
+}
+
+#[test]
+fn next_if_seq() {
+    let mut iter = "->::::".chars().bpeekable::<typenum::U<3>>();
+    assert_eq!(iter.next_if_eq_seq(&[':', ':']), None);
+    // failed match leaves the buffer untouched
+    assert_eq!(iter.buffer(), &['-', '>']);
+    assert_eq!(iter.next_if_eq_seq(&['-', '>']), Some(['-', '>']));
+    assert_eq!(
+        iter.next_if_seq(|[a, b, c]| a == b && b == c),
+        Some([':'; 3])
+    );
+    assert_eq!(iter.next_if_eq_seq(&[':', ':']), None);
+    assert_eq!(iter.collect::<Vec<_>>(), [':']);
+
+    let mut iter = "::x".chars().bpeekable_const::<2>();
+    assert_eq!(iter.next_if_seq::<2>(|pair| pair == &[&'-', &'>']), None);
+    assert_eq!(iter.next_if_eq_seq(&[':', ':']), Some([':', ':']));
+    assert_eq!(iter.next_if_eq_seq(&['x']), Some(['x']));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn peek_nth() {
+    let mut iter = (0..4).bpeekable::<typenum::U<3>>();
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    *iter.peek_nth_mut(2).unwrap() = 20;
+    // beyond the capacity
+    assert_eq!(iter.peek_nth(3), None);
+    assert_eq!(iter.peek_nth_mut(3), None);
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 20, 3]);
+
+    let mut iter = (0..2).bpeekable_const::<3>();
+    // beyond the elements
+    assert_eq!(iter.peek_nth(2), None);
+    assert_eq!(iter.peek_nth(0), Some(&0));
+}
+
+#[test]
+fn dyn_cursor() {
+    let mut iter = (0..5).bpeekable::<typenum::U<3>>();
+    assert!(iter.bpeek_dyn(0).is_none());
+    assert!(iter.bpeek_dyn(4).is_none());
+
+    let cursor = iter.bpeek_dyn(1).unwrap();
+    assert_eq!(*cursor, 0);
+    let cursor = cursor.back().unwrap_err();
+    let cursor = cursor.forward().unwrap().forward().unwrap();
+    assert_eq!((cursor.count(), *cursor), (3, 2));
+    // beyond the capacity
+    let mut cursor = cursor.forward().unwrap_err();
+    *cursor += 10;
+    cursor.peek_all_mut().for_each(|item| *item *= 2);
+    assert!(cursor.peek_all().eq(&[0, 2, 24]));
+    let mut cursor = cursor.back().unwrap();
+    assert_eq!(*cursor, 2);
+    *cursor = 7;
+    assert!(cursor.take_all().eq([0, 7]));
+
+    let cursor = iter.bpeek_dyn(2).unwrap();
+    assert!(cursor.peek_all().eq(&[24, 3]));
+    // beyond the elements
+    let cursor = cursor.forward().unwrap().peek_forward().unwrap_err();
+    assert_eq!(*cursor, 4);
+    assert_eq!(iter.collect::<Vec<_>>(), [24, 3, 4]);
+}
+
+#[test]
+fn buffered_slices() {
+    let mut iter = b"abcde".iter().copied().bpeekable::<typenum::U<3>>();
+    assert_eq!(iter.buffered(), (&[][..], &[][..]));
+    assert_eq!(iter.peek(), Some(&b'a'));
+    assert_eq!(iter.buffered(), (&b"a"[..], &[][..]));
+
+    let (first, second) = iter.fill();
+    assert_eq!([first, second].concat(), b"abc");
+    assert_eq!(iter.next(), Some(b'a'));
+    // wraps around the end of the buffer
+    let (first, second) = iter.fill();
+    assert_eq!([first, second].concat(), b"bcd");
+
+    assert_eq!(iter.nth(2), Some(b'd'));
+    let (first, second) = iter.fill();
+    assert_eq!([first, second].concat(), b"e");
+}
+
+#[test]
+fn bpeek_at_most() {
+    let mut iter = (0..5).bpeekable::<typenum::U<3>>();
+    let mut peek = iter.bpeek_at_most::<3>();
+    assert!(peek.is_complete());
+    assert_eq!(peek.get(3), None);
+    *peek.get_mut(0).unwrap() = 10;
+    assert!(peek.take_all().eq([10, 1, 2]));
+
+    let mut peek = iter.bpeek_at_most::<3>();
+    assert_eq!((peek.count(), peek.is_complete()), (2, false));
+    peek.peek_all_mut().for_each(|item| *item *= 2);
+    assert!(peek.peek_all().eq(&[6, 8]));
+    assert!(iter.is_exhausted());
+    assert_eq!(iter.collect::<Vec<_>>(), [6, 8]);
+
+    let mut iter = core::iter::empty::<u8>().bpeekable_const::<2>();
+    assert!(iter.bpeek_at_most::<2>().is_empty());
+}
+
+#[test]
+fn exhausted() {
+    let mut calls = 0;
+    // yields `None` in between the elements
+    let unfused = core::iter::from_fn(|| {
+        calls += 1;
+        (calls % 2 == 1).then_some(calls)
+    });
+    let mut iter = unfused.bpeekable::<typenum::U<2>>();
+    assert!(!iter.is_exhausted());
+    assert_eq!(iter.bpeek_at_most::<2>().count(), 1);
+    assert!(iter.is_exhausted());
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert!(iter.peek().is_none());
+    drop(iter);
+    // polled only until the first `None`
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn unread() {
+    let mut iter = (0..4).bpeekable::<typenum::U<3>>();
+    let first = iter.next().unwrap();
+    assert_eq!(iter.peek(), Some(&1));
+    iter.unread(first).unwrap();
+    assert_eq!(iter.unread_many([7, 8]), Err([7, 8]));
+    assert_eq!(iter.unread_many([7]), Ok(()));
+    assert_eq!(iter.unread(9), Err(9));
+    assert_eq!(iter.by_ref().take(4).collect::<Vec<_>>(), [7, 0, 1, 2]);
+    // unreading at the end of input is fine
+    let last = iter.next().unwrap();
+    assert_eq!(iter.peek(), None);
+    iter.unread(last).unwrap();
+    assert_eq!(iter.next(), Some(3));
+    // but not after yielding `None`, iterator stays fused
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.unread(3), Err(3));
+    assert_eq!(iter.unread_many([3]), Err([3]));
+    assert_eq!(iter.next(), None);
+
+    // running out of elements while consuming in other ways
+    let mut iter = (0..2).bpeekable::<typenum::U<3>>();
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.unread(42), Err(42));
+    let mut iter = (0..2).bpeekable::<typenum::U<3>>();
+    assert_eq!(iter.find(|_| false), None);
+    assert_eq!(iter.unread(42), Err(42));
+    let mut iter = (0..2).bpeekable::<typenum::U<3>>();
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.next_back(), Some(1));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.unread(42), Err(42));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = "-1".chars().bpeekable_const::<1>();
+    let minus = iter.next_unreadable().unwrap();
+    assert_eq!(*minus, '-');
+    minus.unread();
+    // buffer stays full after peeking
+    assert_eq!(iter.peek(), Some(&'-'));
+    iter.next_unreadable().unwrap().unread();
+    let mut minus = iter.next_unreadable().unwrap();
+    *minus = '+';
+    assert_eq!(minus.take(), '+');
+    assert_eq!(iter.collect::<Vec<_>>(), ['1']);
+
+    // decides based on the following input
+    let mut iter = "a=>>".chars().bpeekable::<typenum::U<3>>();
+    assert_eq!(iter.next(), Some('a'));
+    let mut eq = iter.next_unreadable().unwrap();
+    assert_eq!(eq.peek(), Some(&'>'));
+    assert_eq!(eq.peek_nth(1), Some(&'>'));
+    // place of the held element is kept free
+    assert_eq!(eq.peek_nth(2), None);
+    eq.unread();
+    assert_eq!(iter.collect::<Vec<_>>(), ['=', '>', '>']);
+}
+
+#[test]
+fn history() {
+    // tells unary minus from binary minus
+    let mut iter = "-1-2"
+        .chars()
+        .bpeekable_hist::<typenum::U<1>, typenum::U<2>>();
+    let mut unary = Vec::new();
+    while let Some(c) = iter.next() {
+        if c == '-' {
+            let after_operand = iter.prev2().is_some_and(|prev| prev.is_ascii_digit());
+            unary.push(!after_operand);
+        }
+    }
+    assert_eq!(unary, [true, false]);
+
+    let mut iter = (0..5).bpeekable_hist::<typenum::U<2>, typenum::U<3>>();
+    assert!(iter.prev1().is_none());
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    // peeking is not consuming
+    assert!(iter.history().next().is_none());
+    assert_eq!(iter.next_if_eq(&0), Some(0));
+    assert_eq!(iter.nth(2), Some(3));
+    assert!(iter.history().eq(&[1, 2, 3]));
+
+    let cursor = iter.prev::<2>().unwrap();
+    assert_eq!(*cursor, 2);
+    assert_eq!(cursor.peek_all(), [&2, &3]);
+    let cursor = cursor.further::<3>().unwrap();
+    assert_eq!(*cursor, 1);
+    assert_eq!(*cursor.closer::<2>().closer::<1>(), 3);
+
+    iter.clear_history();
+    assert!(iter.prev1().is_none());
+    assert_eq!(iter.collect::<Vec<_>>(), [4]);
+}
+
+#[test]
+fn history_lookahead() {
+    let mut iter = "a->b=>c"
+        .chars()
+        .bpeekable_hist::<typenum::U<3>, typenum::U<2>>();
+    assert_eq!(iter.next(), Some('a'));
+    // consumed through cursors
+    assert_eq!(
+        iter.bpeek2().map(|cursor| cursor.take_all()),
+        Some(['-', '>'])
+    );
+    assert_eq!(iter.prev::<2>().unwrap().peek_all(), [&'-', &'>']);
+    assert!(iter.next_if_eq_seq(&['b', '=']).is_some());
+    assert!(iter.history().eq(&['b', '=']));
+    let peek = iter.bpeek_at_most::<3>();
+    assert_eq!(peek.count(), 2);
+    assert!(peek.take_all().eq(['>', 'c']));
+    assert_eq!(*iter.prev1().unwrap(), 'c');
+
+    // put back elements are recorded, once consumed
+    iter.unread('x').unwrap();
+    assert!(iter.history().eq(&['>', 'c']));
+    assert_eq!(iter.next(), Some('x'));
+    assert!(iter.history().eq(&['c', 'x']));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = "abc"
+        .chars()
+        .bpeekable_hist::<typenum::U<3>, typenum::U<3>>();
+    assert_eq!(iter.next(), Some('a'));
+    assert_eq!(iter.next(), Some('b'));
+    iter.unread('z').unwrap();
+    assert_eq!(iter.next(), Some('z'));
+    assert!(iter.history().eq(&['a', 'b', 'z']));
+    assert_eq!(*iter.prev1().unwrap(), 'z');
+
+    assert_eq!(iter.peek_nth(0), Some(&'c'));
+    iter.unread_many(['x', 'y']).unwrap();
+    assert_eq!(iter.unread_many(['w']), Err(['w']));
+    assert_eq!(iter.by_ref().take(2).collect::<Vec<_>>(), ['x', 'y']);
+    assert!(iter.history().eq(&['z', 'x', 'y']));
+    // putting back the same element takes it out of the history
+    iter.next_unreadable().unwrap().unread();
+    assert!(iter.history().eq(&['z', 'x', 'y']));
+    assert_eq!(iter.collect::<Vec<_>>(), ['c']);
+}
@@ -0,0 +1,134 @@
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{dequeue::Capacity, error::unwrap_invariant};
+
+use super::BPeekN;
+
+impl<I: Iterator, N: Capacity> BPeekN<I, N> {
+    /// Puts an element back in front of the iterator, so that it is yielded next.
+    ///
+    /// Fails, handing the element back, if the buffer is full,
+    /// or the iterator has run out of elements while consuming, since it may be fused.
+    #[inline]
+    pub fn unread(&mut self, item: I::Item) -> Result<(), I::Item> {
+        if self.finished {
+            return Err(item);
+        }
+        self.queue.push_front(item).into_result()
+    }
+
+    /// Puts `K` elements back in front of the iterator, so that they are yielded next, in order.
+    ///
+    /// Fails, handing the elements back, if the buffer does not have space for all of them,
+    /// or the iterator has run out of elements while consuming, since it may be fused.
+    pub fn unread_many<const K: usize>(&mut self, items: [I::Item; K]) -> Result<(), [I::Item; K]> {
+        if self.finished || N::CAPACITY - self.queue.len() < K {
+            return Err(items);
+        }
+        for item in items.into_iter().rev() {
+            // SAFETY: free space is checked above
+            unsafe {
+                unwrap_invariant(
+                    self.queue.push_front(item).into_result().ok(),
+                    "Must be able to push, free space is checked above",
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Consumes the next element, keeping its place in the buffer, so that it can always be put back.
+    ///
+    /// Buffer must have space for at least one element, which is checked at compile time.
+    /// While the element is held, lookahead is limited to one element less than the buffer capacity,
+    /// so to peek `K` elements before deciding, buffer needs a headroom of one element on top of that.
+    #[inline]
+    pub fn next_unreadable(&mut self) -> Option<Unreadable<'_, I, N>> {
+        const {
+            assert!(
+                N::CAPACITY >= 1,
+                "Unreading needs space for at least one element in the buffer"
+            );
+        }
+        let item = self.next()?;
+        Some(Unreadable { iter: self, item })
+    }
+}
+
+/// Consumed element, that can be put back infallibly, see [`BPeekN::next_unreadable`].
+///
+/// Holds the iterator borrowed, so nothing can take the place the element was consumed from.
+pub struct Unreadable<'iter, I: Iterator, N: Capacity> {
+    iter: &'iter mut BPeekN<I, N>,
+    item: I::Item,
+}
+
+impl<I: Iterator, N: Capacity> Debug for Unreadable<'_, I, N>
+where
+    I: Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Unreadable")
+            .field("iter", &*self.iter)
+            .field("item", &self.item)
+            .finish()
+    }
+}
+
+impl<I: Iterator, N: Capacity> Deref for Unreadable<'_, I, N> {
+    type Target = I::Item;
+
+    fn deref(&self) -> &Self::Target {
+        &self.item
+    }
+}
+
+impl<I: Iterator, N: Capacity> DerefMut for Unreadable<'_, I, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.item
+    }
+}
+
+impl<I: Iterator, N: Capacity> Unreadable<'_, I, N> {
+    /// Keeps the element consumed.
+    #[inline]
+    pub fn take(self) -> I::Item {
+        self.item
+    }
+
+    /// Returns a reference to the next element after the held one.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0)
+    }
+
+    /// Returns a reference to the `n`th element after the held one, counting from zero.
+    ///
+    /// Returns `None`, if there are not enough elements, or it lies beyond the buffer capacity, less the place kept for the held element.
+    #[inline]
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        if n >= N::CAPACITY - 1 {
+            return None;
+        }
+        self.iter.fill_to(n + 1).ok()?;
+        self.iter.queue.get(n)
+    }
+
+    /// Puts the element back in front of the iterator, so that it is yielded next.
+    #[inline]
+    pub fn unread(self) {
+        // SAFETY:
+        // Element was either taken from the buffer, or the buffer was empty and has space for at least one element.
+        // Iterator is borrowed since then, and peeking through the guard leaves one place free.
+        unsafe {
+            unwrap_invariant(
+                self.iter.queue.push_front(self.item).into_result().ok(),
+                "Must be able to push, the place of the element is kept free",
+            );
+        }
+    }
+}
@@ -1,8 +1,18 @@
 #![no_std]
 
-#[cfg(any(test, kani))]
+#[cfg(any(test, kani, feature = "alloc"))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+pub mod dequeue;
+mod error;
 pub mod iterator;
+#[cfg(target_has_atomic = "ptr")]
+pub mod spsc;
 
-mod dequeue;
+pub use error::Error;
+
+pub use dequeue::{
+    Capacity, CapacityError, ConstCapacity, Dequeue as RingDeque, Leftovers, PushStatus, RingLog,
+};
@@ -0,0 +1,247 @@
+use core::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    mem::MaybeUninit,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::dequeue::{Capacity, PushStatus};
+
+/// Lock-free single-producer single-consumer queue, with the same storage as [`Dequeue`](crate::dequeue::Dequeue).
+///
+/// Use [`Spsc::split`] to obtain the [`Producer`] and [`Consumer`] halves, that may be sent to different threads (or interrupt handlers).
+pub struct Spsc<T, N: Capacity> {
+    data: UnsafeCell<N::Storage<T>>,
+    /// Position of the next element to be popped, in range from `0` to `2*N` exclusive
+    head: AtomicUsize,
+    /// Position of the next element to be pushed, in range from `0` to `2*N` exclusive
+    tail: AtomicUsize,
+}
+
+impl<T, N: Capacity> Debug for Spsc<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Spsc")
+            .field("head", &self.head.load(Ordering::Relaxed))
+            .field("tail", &self.tail.load(Ordering::Relaxed))
+            .field("LEN", &N::CAPACITY)
+            .finish()
+    }
+}
+
+// positions are kept in range `0..2*N`, so that full and empty states are distinguishable without wasting a slot
+impl<T, N: Capacity> Spsc<T, N> {
+    #[inline]
+    #[allow(clippy::uninit_assumed_init)] // `Capacity` is sealed, all storages are arrays of `MaybeUninit`
+    const fn uninit_storage() -> N::Storage<T> {
+        // SAFETY:
+        // Storage is an array of `MaybeUninit`, so it does not require initialization
+        unsafe { MaybeUninit::<N::Storage<T>>::uninit().assume_init() }
+    }
+
+    /// Creates an empty queue.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: UnsafeCell::new(Self::uninit_storage()),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Splits the queue into the producing and consuming halves.
+    #[inline]
+    pub fn split(&mut self) -> (Producer<'_, T, N>, Consumer<'_, T, N>) {
+        (Producer { queue: self }, Consumer { queue: self })
+    }
+
+    /// Maximum number of elements this queue can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N::CAPACITY
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        Self::distance(
+            self.head.load(Ordering::Acquire),
+            self.tail.load(Ordering::Acquire),
+        )
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    const fn distance(head: usize, tail: usize) -> usize {
+        if tail >= head {
+            tail - head
+        } else {
+            tail + 2 * N::CAPACITY - head
+        }
+    }
+
+    #[inline]
+    const fn advance(pos: usize) -> usize {
+        if pos + 1 == 2 * N::CAPACITY {
+            0
+        } else {
+            pos + 1
+        }
+    }
+
+    #[inline]
+    fn slot(&self, pos: usize) -> *mut MaybeUninit<T> {
+        let index = if pos >= N::CAPACITY {
+            pos - N::CAPACITY
+        } else {
+            pos
+        };
+        // SAFETY:
+        // Storage is an array of `MaybeUninit<T>`, so it may be accessed through the pointer to its first element.
+        // No references to the whole storage are created, since the other half may access a different slot concurrently.
+        // Index is in bounds, since positions are always less than `2*N`.
+        unsafe { self.data.get().cast::<MaybeUninit<T>>().add(index) }
+    }
+}
+
+impl<T, N: Capacity> Default for Spsc<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, N: Capacity> Drop for Spsc<T, N> {
+    fn drop(&mut self) {
+        let mut head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        while head != tail {
+            // SAFETY: positions from head to tail contain valid data
+            unsafe { (*self.slot(head)).assume_init_drop() };
+            head = Self::advance(head);
+        }
+    }
+}
+
+/// Pushing half of the [`Spsc`] queue.
+pub struct Producer<'q, T, N: Capacity> {
+    queue: &'q Spsc<T, N>,
+}
+
+// SAFETY: only the producer writes to the free slots, and elements are moved to the consumer
+unsafe impl<T: Send, N: Capacity> Send for Producer<'_, T, N> {}
+
+impl<T, N: Capacity> Debug for Producer<'_, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Producer")
+            .field("queue", self.queue)
+            .finish()
+    }
+}
+
+impl<T, N: Capacity> Producer<'_, T, N> {
+    /// Appends an element to the back, rejecting it if the queue is full.
+    pub fn push(&mut self, item: T) -> PushStatus<T> {
+        // tail is only written by the producer
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let head = self.queue.head.load(Ordering::Acquire);
+        if Spsc::<T, N>::distance(head, tail) == N::CAPACITY {
+            return PushStatus::Rejected(item);
+        }
+
+        // SAFETY:
+        // Queue is not full, so position at tail is free, and consumer does not read it until the tail is published
+        unsafe { (*self.queue.slot(tail)).write(item) };
+        self.queue
+            .tail
+            .store(Spsc::<T, N>::advance(tail), Ordering::Release);
+
+        PushStatus::Success
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.queue.len() == N::CAPACITY
+    }
+}
+
+/// Popping half of the [`Spsc`] queue.
+///
+/// As an iterator, it yields `None` when the queue is empty, but may yield more elements once producer pushes them.
+/// When wrapped in [`BPeekN`](crate::iterator::BPeekN), it is not polled again after yielding `None`,
+/// until [`BPeekN::resume`](crate::iterator::BPeekN::resume) is called.
+pub struct Consumer<'q, T, N: Capacity> {
+    queue: &'q Spsc<T, N>,
+}
+
+// SAFETY: only the consumer reads from the occupied slots, and elements are moved from the producer
+unsafe impl<T: Send, N: Capacity> Send for Consumer<'_, T, N> {}
+
+impl<T, N: Capacity> Debug for Consumer<'_, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Consumer")
+            .field("queue", self.queue)
+            .finish()
+    }
+}
+
+impl<T, N: Capacity> Consumer<'_, T, N> {
+    /// Removes an element from the front, if there is one.
+    pub fn pop(&mut self) -> Option<T> {
+        // head is only written by the consumer
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+
+        // SAFETY:
+        // Queue is not empty, so position at head contains valid data, and producer does not overwrite it until the head is published
+        let item = unsafe { (*self.queue.slot(head)).assume_init_read() };
+        self.queue
+            .head
+            .store(Spsc::<T, N>::advance(head), Ordering::Release);
+
+        Some(item)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl<T, N: Capacity> Iterator for Consumer<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pop()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // producer may push more elements at any time
+        (self.len(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,146 @@
+extern crate std;
+
+use alloc::{boxed::Box, vec::Vec};
+use generic_array::typenum;
+use std::thread;
+
+use super::Spsc;
+use crate::iterator::BPeekExt;
+
+#[test]
+fn push_pop() {
+    let mut queue = Spsc::<Box<u8>, typenum::U<3>>::new();
+    let (mut producer, mut consumer) = queue.split();
+
+    assert!(consumer.pop().is_none());
+    producer.push(Box::new(1)).assert();
+    producer.push(Box::new(2)).assert();
+    producer.push(Box::new(3)).assert();
+    assert!(producer.is_full());
+    assert!(producer.push(Box::new(4)).is_rejected());
+
+    assert_eq!(consumer.pop().map(|v| *v), Some(1));
+    producer.push(Box::new(4)).assert();
+    assert_eq!(consumer.len(), 3);
+
+    // rest are dropped with the queue
+}
+
+#[test]
+fn wrap_around() {
+    let mut queue = Spsc::<u32, typenum::U<5>>::new();
+    let (mut producer, mut consumer) = queue.split();
+
+    for i in 0..100 {
+        producer.push(i).assert();
+        producer.push(i + 1000).assert();
+        assert_eq!(consumer.pop(), Some(i));
+        assert_eq!(consumer.pop(), Some(i + 1000));
+        assert!(consumer.is_empty());
+    }
+}
+
+#[test]
+fn consumer_bpeekable() {
+    let mut queue = Spsc::<char, typenum::U<8>>::new();
+    let (mut producer, consumer) = queue.split();
+    for c in "->x".chars() {
+        producer.push(c).assert();
+    }
+
+    let mut peekable = consumer.bpeekable2();
+    assert_eq!(
+        peekable.bpeek2().map(|cursor| cursor.take_all()),
+        Some(['-', '>'])
+    );
+    assert_eq!(peekable.next(), Some('x'));
+    assert_eq!(peekable.next(), None);
+}
+
+#[test]
+fn consumer_bpeekable_resume() {
+    let mut queue = Spsc::<u8, typenum::U<4>>::new();
+    let (mut producer, consumer) = queue.split();
+
+    let mut peekable = consumer.bpeekable2();
+    assert_eq!(peekable.next(), None);
+    producer.push(1).assert();
+    // not polled again, until resumed
+    assert_eq!(peekable.next(), None);
+    assert!(peekable.is_exhausted());
+    assert_eq!(peekable.unread(0), Err(0));
+    peekable.resume();
+    assert_eq!(peekable.unread(0), Ok(()));
+    assert_eq!(peekable.next(), Some(0));
+    assert_eq!(peekable.next(), Some(1));
+
+    assert_eq!(peekable.peek(), None);
+    producer.push(2).assert();
+    producer.push(3).assert();
+    peekable.resume();
+    assert_eq!(
+        peekable.bpeek2().map(|cursor| cursor.take_all()),
+        Some([2, 3])
+    );
+}
+
+#[test]
+fn threads_stress() {
+    const COUNT: usize = 200_000;
+
+    let mut queue = Spsc::<Box<usize>, typenum::U<7>>::new();
+    let (mut producer, mut consumer) = queue.split();
+
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            for i in 0..COUNT {
+                let mut item = Box::new(i);
+                loop {
+                    match producer.push(item) {
+                        crate::PushStatus::Success => break,
+                        crate::PushStatus::Rejected(rejected) => {
+                            item = rejected;
+                            thread::yield_now();
+                        }
+                    }
+                }
+            }
+        });
+
+        let consumer = scope.spawn(move || {
+            let mut received = Vec::with_capacity(COUNT);
+            while received.len() < COUNT {
+                match consumer.pop() {
+                    Some(item) => received.push(*item),
+                    None => thread::yield_now(),
+                }
+            }
+            received
+        });
+
+        let received = consumer.join().unwrap();
+        assert!(received.into_iter().eq(0..COUNT));
+    });
+}
+
+#[test]
+fn threads_stress_drop() {
+    // consumer stops early, rest of elements should be dropped with the queue
+    for _ in 0..100 {
+        let mut queue = Spsc::<Box<usize>, typenum::U<16>>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                for i in 0..64 {
+                    let _ = producer.push(Box::new(i));
+                }
+            });
+            scope.spawn(move || {
+                for _ in 0..8 {
+                    let _ = consumer.pop();
+                }
+            });
+        });
+    }
+}